@@ -1,31 +1,343 @@
 use std::{error::Error as StdError, fmt, path::PathBuf};
 
-use crate::quantities::Vec3;
+use crate::quantities::{Angle, Length, Point2D, Point3D, Vec2, Vec3};
 
 /// The errors that can occurr.
 #[derive(Clone, Debug, PartialEq)]
 pub enum Error {
+    /// Occurs when an arc that ends at a given point could not be constructed.
+    Arc(Point2D),
+
+    /// Occurs when an arc in 3D space that ends at a given point could not be constructed.
+    Arc3D(Point3D),
+
+    /// Occurs when a Bezier curve is constructed from a number of control points that does not
+    /// describe a curve.
+    Bezier(usize),
+
+    /// Occurs when a chamfer with a given distance could not be applied to a `Part` or `Sketch`.
+    Chamfer(Length),
+
+    /// Wraps another `Error` with the operation that failed and the object it was applied to.
+    Context {
+        /// The operation that failed, e.g. "loft".
+        operation: String,
+        /// The object the operation was applied to, e.g. "profile 2".
+        subject: String,
+        /// The underlying error.
+        source: Box<Error>,
+    },
+
+    /// Occurs when an operation that requires sketches on different planes is called with
+    /// sketches that lie on the same plane.
+    CoplanarSketches,
+
+    /// Occurs when an `Edge` is added to a path that ends at a different point than where the
+    /// `Edge` starts.
+    DiscontinuousPath(Point2D, Point2D),
+
     /// Occurs when a function that requires a non-empty `Part` is called on an empty one.
     EmptyPart,
 
+    /// Occurs when a function that requires a non-empty path is called on an empty one.
+    EmptyPath,
+
     /// Occurs when a function that requires a non-empty `Sketch` is called on an empty one.
     EmptySketch,
 
+    /// Occurs when a fillet with a given radius could not be applied to a `Part` or `Sketch`.
+    Fillet(Length),
+
+    /// Occurs when a helix that starts at a given point could not be constructed.
+    Helix(Point3D),
+
+    /// Occurs when a line in 3D space that ends at a given point could not be constructed.
+    Line3D(Point3D),
+
+    /// Occurs when a series of profiles could not be connected into a `Part`.
+    Loft,
+
+    /// Occurs when an operation that requires a flat `Face` is called with a curved one.
+    NonPlanarFace,
+
+    /// Occurs when a cut or extrusion on a `Face` is requested with a given depth that is not
+    /// positive.
+    NonPositiveDepth(Length),
+
+    /// Occurs when an operation that requires at least two profiles is called with a given number
+    /// of them.
+    NotEnoughProfiles(usize),
+
+    /// Occurs when a mesh that is read from a given path does not enclose a volume.
+    NotWatertight(PathBuf),
+
+    /// Occurs when the outline of a `Sketch` could not be offset by a given distance.
+    Offset(Length),
+
+    /// Occurs when a `Sketch` could not be revolved by a given angle.
+    Revolve(Angle),
+
+    /// Occurs when a `Part` could not be hollowed out with a given wall thickness.
+    Shell(Length),
+
+    /// Occurs when a spline is interpolated through a number of points that does not describe a
+    /// curve.
+    Spline(usize),
+
+    /// Occurs when a `Part` could not be read from a .step file at a given path.
+    StepRead(PathBuf),
+
     /// Occurs when a `Part` could not be written to a .step file at a given path.
     StepWrite(PathBuf),
 
+    /// Occurs when a `Part` could not be read from a .stl file at a given path.
+    StlRead(PathBuf),
+
     /// Occurs when a `Part` could not be written to a .stl file at a given path.
     StlWrite(PathBuf),
 
+    /// Occurs when a `Sketch` could not be swept along a path.
+    Sweep,
+
+    /// Occurs when a path could not be thickened by a given thickness.
+    Thicken(Length),
+
+    /// Occurs when the edges of a path could not be joined into a continuous wire.
+    Wire,
+
     /// Occurs when an operation that requires a length is performed on a `Vec3` with a magnitude of zero.
     ZeroVector(Vec3),
 
+    /// Occurs when an operation that requires a length is performed on a `Vec2` with a magnitude of zero.
+    ZeroVector2D(Vec2),
+
     /// Occurs when two vectors that are required to be orthogonal, are not.
     VectorsNotOrthogonal(Vec3, Vec3),
 }
-impl StdError for Error {}
+impl Error {
+    /// Wrap this `Error` with the operation that failed and the object it was applied to.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Error, length, Part};
+    ///
+    /// let error = Part::empty()
+    ///     .fillet(length!(1 mm))
+    ///     .map_err(|err| err.context("fillet", "lid"))
+    ///     .unwrap_err();
+    /// assert_eq!(error.to_string(), "fillet of lid failed: the part is empty");
+    /// assert_eq!(error.root_cause(), &Error::EmptyPart);
+    /// ```
+    pub fn context(self, operation: impl Into<String>, subject: impl Into<String>) -> Self {
+        Error::Context {
+            operation: operation.into(),
+            subject: subject.into(),
+            source: Box::new(self),
+        }
+    }
+
+    /// Return the innermost `Error` that is wrapped by any number of contexts.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::Error;
+    ///
+    /// let error = Error::EmptySketch.context("extrude", "base").context("build", "housing");
+    /// assert_eq!(error.root_cause(), &Error::EmptySketch);
+    /// ```
+    pub fn root_cause(&self) -> &Error {
+        match self {
+            Error::Context { source, .. } => source.root_cause(),
+            _ => self,
+        }
+    }
+}
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Error::Context { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Under construction")
+        match self {
+            Error::Arc(end) => write!(
+                f,
+                "an arc ending at {} could not be constructed",
+                fmt_point(end)
+            ),
+            Error::Arc3D(end) => write!(
+                f,
+                "an arc ending at {} could not be constructed",
+                fmt_point_3d(end)
+            ),
+            Error::Bezier(count) => write!(
+                f,
+                "a Bezier curve can not be constructed from {count} control points"
+            ),
+            Error::Chamfer(distance) => write!(
+                f,
+                "a chamfer with a distance of {} m could not be applied",
+                distance.m()
+            ),
+            Error::Context {
+                operation,
+                subject,
+                source,
+            } => write!(f, "{operation} of {subject} failed: {source}"),
+            Error::CoplanarSketches => write!(f, "the sketches lie on the same plane"),
+            Error::DiscontinuousPath(path_end, edge_start) => write!(
+                f,
+                "the path ends at {} but the next edge starts at {}",
+                fmt_point(path_end),
+                fmt_point(edge_start)
+            ),
+            Error::EmptyPart => write!(f, "the part is empty"),
+            Error::EmptyPath => write!(f, "the path is empty"),
+            Error::EmptySketch => write!(f, "the sketch is empty"),
+            Error::Fillet(radius) => write!(
+                f,
+                "a fillet with a radius of {} m could not be applied",
+                radius.m()
+            ),
+            Error::Helix(start) => write!(
+                f,
+                "a helix starting at {} could not be constructed",
+                fmt_point_3d(start)
+            ),
+            Error::Line3D(end) => write!(
+                f,
+                "a line ending at {} could not be constructed",
+                fmt_point_3d(end)
+            ),
+            Error::Loft => write!(f, "the profiles could not be connected"),
+            Error::NonPlanarFace => write!(f, "the face is not planar"),
+            Error::NonPositiveDepth(depth) => {
+                write!(f, "a depth of {} m is not possible", depth.m())
+            }
+            Error::NotEnoughProfiles(count) => write!(
+                f,
+                "at least two profiles are required but {count} were given"
+            ),
+            Error::NotWatertight(path) => write!(
+                f,
+                "the mesh in {} does not enclose a volume",
+                path.display()
+            ),
+            Error::Offset(distance) => {
+                write!(f, "the sketch could not be offset by {} m", distance.m())
+            }
+            Error::Revolve(angle) => write!(
+                f,
+                "a revolution by {} deg could not be constructed",
+                angle.deg()
+            ),
+            Error::Shell(thickness) => write!(
+                f,
+                "a shell with a wall thickness of {} m could not be constructed",
+                thickness.m()
+            ),
+            Error::Spline(count) => {
+                write!(f, "a spline can not be interpolated through {count} points")
+            }
+            Error::StepRead(path) => write!(f, "could not read STEP file {}", path.display()),
+            Error::StepWrite(path) => write!(f, "could not write STEP file {}", path.display()),
+            Error::StlRead(path) => write!(f, "could not read STL file {}", path.display()),
+            Error::StlWrite(path) => write!(f, "could not write STL file {}", path.display()),
+            Error::Sweep => write!(f, "the sketch could not be swept along the path"),
+            Error::Thicken(thickness) => {
+                write!(f, "the path could not be thickened by {} m", thickness.m())
+            }
+            Error::Wire => write!(f, "the edges could not be joined into a continuous wire"),
+            Error::ZeroVector(vec) => {
+                write!(f, "the vector {} has a magnitude of zero", fmt_vec(vec))
+            }
+            Error::ZeroVector2D(vec) => {
+                write!(f, "the vector {} has a magnitude of zero", fmt_vec_2d(vec))
+            }
+            Error::VectorsNotOrthogonal(vec1, vec2) => write!(
+                f,
+                "the vectors {} and {} are not orthogonal",
+                fmt_vec(vec1),
+                fmt_vec(vec2)
+            ),
+        }
+    }
+}
+
+fn fmt_point(point: &Point2D) -> String {
+    format!("({} m, {} m)", point.x.m(), point.y.m())
+}
+
+fn fmt_point_3d(point: &Point3D) -> String {
+    format!("({} m, {} m, {} m)", point.x.m(), point.y.m(), point.z.m())
+}
+
+fn fmt_vec(vec: &Vec3) -> String {
+    format!("({}, {}, {})", vec.x, vec.y, vec.z)
+}
+
+fn fmt_vec_2d(vec: &Vec2) -> String {
+    format!("({}, {})", vec.x, vec.y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::length;
+
+    #[test]
+    fn display_path() {
+        assert_eq!(
+            Error::StepWrite(PathBuf::from("out/part.step")).to_string(),
+            "could not write STEP file out/part.step"
+        )
+    }
+
+    #[test]
+    fn display_length() {
+        assert_eq!(
+            Error::Fillet(length!(2 mm)).to_string(),
+            "a fillet with a radius of 0.002 m could not be applied"
+        )
+    }
+
+    #[test]
+    fn display_points() {
+        assert_eq!(
+            Error::DiscontinuousPath(Point2D::from_m(1., 0.), Point2D::from_m(2., 0.5)).to_string(),
+            "the path ends at (1 m, 0 m) but the next edge starts at (2 m, 0.5 m)"
+        )
+    }
+
+    #[test]
+    fn display_vectors() {
+        assert_eq!(
+            Error::VectorsNotOrthogonal(Vec3::from((1., 0., 0.)), Vec3::from((1., 1., 0.)))
+                .to_string(),
+            "the vectors (1, 0, 0) and (1, 1, 0) are not orthogonal"
+        )
+    }
+
+    #[test]
+    fn display_nested_context() {
+        let error = Error::EmptySketch
+            .context("extrude", "base")
+            .context("build", "housing");
+        assert_eq!(
+            error.to_string(),
+            "build of housing failed: extrude of base failed: the sketch is empty"
+        )
+    }
+
+    #[test]
+    fn source_of_context() {
+        let error = Error::EmptyPart.context("fillet", "lid");
+        assert_eq!(
+            error.source().map(|source| source.to_string()),
+            Some("the part is empty".into())
+        )
     }
 }
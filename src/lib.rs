@@ -6,14 +6,21 @@ mod errors;
 mod parts;
 mod quantities;
 mod sketches;
+mod topology;
 
 pub use errors::Error;
 pub use parts::{
-    primitives::{Cuboid, Cylinder, Sphere},
-    Part,
+    primitives::{Cone, Cuboid, Cylinder, Prism, Sphere, Torus, Wedge},
+    CutDepth, LoftMode, MassProperties, Part, Path3D, StlOptions, SweepOrientation,
+};
+pub use quantities::{
+    Angle, Area, Axis, Density, Length, Mass, MomentOfInertia, Plane, Point2D, Point3D, Vec2, Vec3,
+    Volume,
 };
-pub use quantities::{Angle, Axis, Length, Plane, Point2D, Point3D, Vec3};
 pub use sketches::{
-    primitives::{Circle, Rectangle},
-    Edge, Path, Sketch,
+    primitives::{Circle, Ellipse, Polygon, Rectangle, RoundedRectangle, Slot},
+    ArcDirection, Edge, EndCap, JoinStyle, Path, Sketch, ThickenSide,
+};
+pub use topology::{
+    CurveType, Edge3D, EdgeSelector, Face, FaceSelector, SurfaceType, Vertex,
 };
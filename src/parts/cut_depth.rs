@@ -0,0 +1,11 @@
+use crate::Length;
+
+/// How deep a pocket is cut into a `Part`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum CutDepth {
+    /// Cut down to a fixed depth below the face.
+    Blind(Length),
+
+    /// Cut through the whole `Part`.
+    ThroughAll,
+}
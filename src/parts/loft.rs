@@ -0,0 +1,9 @@
+/// The way in which the surface of a loft passes through its profiles.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum LoftMode {
+    /// Connect consecutive profiles with straight ruled surfaces.
+    Ruled,
+
+    /// Connect all profiles with a single smooth surface.
+    Smooth,
+}
@@ -0,0 +1,272 @@
+use crate::{Area, Axis, Density, Error, Length, Mass, MomentOfInertia, Point3D, Vec3, Volume};
+
+/// The maximum number of sweeps of the Jacobi eigenvalue algorithm.
+const MAX_JACOBI_SWEEPS: usize = 50;
+
+/// The mass properties of a `Part` with a uniform density.
+///
+/// # Example
+/// ```rust
+/// use anvil::{Cuboid, Density, length};
+///
+/// let cuboid = Cuboid::from_dim(length!(1 m), length!(2 m), length!(3 m));
+/// let properties = cuboid.mass_properties(Density::from_g_cm3(1.)).unwrap();
+/// assert!((properties.area.m2() - 22.).abs() < 1e-9);
+/// assert!((properties.mass.kg() - 6000.).abs() < 1e-6);
+/// assert!((properties.inertia[2][2].kg_m2() - 2500.).abs() < 1e-6);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct MassProperties {
+    /// The surface area of the `Part`.
+    pub area: Area,
+
+    /// The center of mass of the `Part`.
+    pub center: Point3D,
+
+    /// The density of the material of the `Part`.
+    pub density: Density,
+
+    /// The inertia tensor around the center of mass.
+    ///
+    /// The rows and columns correspond to the x, y and z axes. The off-diagonal entries are the
+    /// negated products of inertia.
+    pub inertia: [[MomentOfInertia; 3]; 3],
+
+    /// The mass of the `Part`.
+    pub mass: Mass,
+
+    /// The volume of the `Part`.
+    pub volume: Volume,
+}
+impl MassProperties {
+    /// Return the moment of inertia around an `Axis`.
+    ///
+    /// The `Axis` does not need to pass through the center of mass, the parallel axis theorem is
+    /// applied. If the direction of the `Axis` is zero, an `Err(Error::ZeroVector)` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Axis, Cuboid, Density, length, Point3D};
+    ///
+    /// let cuboid = Cuboid::from_dim(length!(1 m), length!(2 m), length!(3 m));
+    /// let properties = cuboid.mass_properties(Density::from_kg_m3(1000.)).unwrap();
+    /// let edge = Axis::new(Point3D::from_m(0.5, 1., 0.), (0., 0., 1.));
+    /// let moment = properties.moment_of_inertia(&edge).unwrap();
+    /// assert!((moment.kg_m2() - 10000.).abs() < 1e-6);
+    /// ```
+    pub fn moment_of_inertia(&self, axis: &Axis) -> Result<MomentOfInertia, Error> {
+        let direction = axis.direction.normalize()?;
+        let direction = [direction.x, direction.y, direction.z];
+
+        let mut central_moment = MomentOfInertia::zero();
+        for (row, direction_row) in self.inertia.iter().zip(direction) {
+            for (entry, direction_col) in row.iter().zip(direction) {
+                central_moment = central_moment + *entry * (direction_row * direction_col);
+            }
+        }
+
+        let offset = (self.center - axis.origin) / Length::from_m(1.);
+        let distance = offset.cross(Vec3::from((direction[0], direction[1], direction[2])));
+        Ok(central_moment + MomentOfInertia::from_kg_m2(self.mass.kg() * distance.dot(distance)))
+    }
+    /// Return the axes through the center of mass around which the products of inertia vanish.
+    ///
+    /// The axes are ordered like `MassProperties::principal_moments`. Their directions are
+    /// normalized and mutually orthogonal.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, Density, length};
+    ///
+    /// let cuboid = Cuboid::from_dim(length!(1 m), length!(2 m), length!(3 m));
+    /// let properties = cuboid.mass_properties(Density::from_kg_m3(1000.)).unwrap();
+    /// let smallest = &properties.principal_axes()[0];
+    /// assert!((smallest.direction.z.abs() - 1.).abs() < 1e-9);
+    /// ```
+    pub fn principal_axes(&self) -> [Axis; 3] {
+        let (_, directions) = symmetric_eigen(self.inertia_kg_m2());
+        directions.map(|direction| Axis {
+            origin: self.center,
+            direction,
+        })
+    }
+    /// Return the moments of inertia around the principal axes.
+    ///
+    /// The moments are sorted in ascending order.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, Density, length};
+    ///
+    /// let cuboid = Cuboid::from_dim(length!(1 m), length!(2 m), length!(3 m));
+    /// let properties = cuboid.mass_properties(Density::from_kg_m3(1000.)).unwrap();
+    /// let moments = properties.principal_moments();
+    /// assert!((moments[0].kg_m2() - 2500.).abs() < 1e-6);
+    /// assert!((moments[1].kg_m2() - 5000.).abs() < 1e-6);
+    /// assert!((moments[2].kg_m2() - 6500.).abs() < 1e-6);
+    /// ```
+    pub fn principal_moments(&self) -> [MomentOfInertia; 3] {
+        let (moments, _) = symmetric_eigen(self.inertia_kg_m2());
+        moments.map(MomentOfInertia::from_kg_m2)
+    }
+    /// Return the distance from an `Axis` at which the whole mass would have the same moment of
+    /// inertia.
+    ///
+    /// If the direction of the `Axis` is zero, an `Err(Error::ZeroVector)` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Axis, Cylinder, Density, length};
+    ///
+    /// let cylinder = Cylinder::from_radius(length!(1 m), length!(1 m));
+    /// let properties = cylinder.mass_properties(Density::from_kg_m3(1000.)).unwrap();
+    /// let radius = properties.radius_of_gyration(&Axis::z()).unwrap();
+    /// assert!((radius.m() - 0.5_f64.sqrt()).abs() < 1e-6);
+    /// ```
+    pub fn radius_of_gyration(&self, axis: &Axis) -> Result<Length, Error> {
+        let moment = self.moment_of_inertia(axis)?;
+        if self.mass == Mass::zero() {
+            return Ok(Length::zero());
+        }
+        Ok(Length::from_m((moment / self.mass).m2().sqrt()))
+    }
+    /// Return the entries of the inertia tensor in kilogram square meters.
+    fn inertia_kg_m2(&self) -> [[f64; 3]; 3] {
+        self.inertia.map(|row| row.map(|entry| entry.kg_m2()))
+    }
+}
+
+/// Return the eigenvalues in ascending order and the corresponding normalized eigenvectors of a
+/// symmetric 3x3 matrix.
+///
+/// The cyclic Jacobi eigenvalue algorithm is used, which is robust for the small and well
+/// conditioned matrices that describe mass distributions.
+fn symmetric_eigen(matrix: [[f64; 3]; 3]) -> ([f64; 3], [Vec3; 3]) {
+    let mut a = matrix;
+    let mut v = [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]];
+
+    for _ in 0..MAX_JACOBI_SWEEPS {
+        let off_diagonal = a[0][1].powi(2) + a[0][2].powi(2) + a[1][2].powi(2);
+        let norm = a.iter().flatten().map(|entry| entry.powi(2)).sum::<f64>();
+        if off_diagonal <= f64::EPSILON.powi(2) * norm {
+            break;
+        }
+
+        for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+            if a[p][q] == 0. {
+                continue;
+            }
+            let theta = (a[q][q] - a[p][p]) / (2. * a[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + 1.).sqrt());
+            let c = 1. / (t * t + 1.).sqrt();
+            let s = t * c;
+
+            for row in a.iter_mut().chain(v.iter_mut()) {
+                let (kp, kq) = (row[p], row[q]);
+                row[p] = c * kp - s * kq;
+                row[q] = s * kp + c * kq;
+            }
+            for k in 0..3 {
+                let (pk, qk) = (a[p][k], a[q][k]);
+                a[p][k] = c * pk - s * qk;
+                a[q][k] = s * pk + c * qk;
+            }
+        }
+    }
+
+    let mut order = [0, 1, 2];
+    order.sort_by(|&i, &j| a[i][i].total_cmp(&a[j][j]));
+    (
+        order.map(|i| a[i][i]),
+        order.map(|i| Vec3::from((v[0][i], v[1][i], v[2][i]))),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cuboid, Part, Sphere, angle, length};
+
+    use super::*;
+
+    #[test]
+    fn eigen_of_diagonal_matrix() {
+        let (values, vectors) = symmetric_eigen([[3., 0., 0.], [0., 1., 0.], [0., 0., 2.]]);
+        assert_eq!(values, [1., 2., 3.]);
+        assert_eq!(
+            vectors,
+            [
+                Vec3::from((0., 1., 0.)),
+                Vec3::from((0., 0., 1.)),
+                Vec3::from((1., 0., 0.))
+            ]
+        );
+    }
+
+    #[test]
+    fn eigen_of_full_matrix() {
+        let matrix = [[4., 1., 2.], [1., 3., 0.5], [2., 0.5, 5.]];
+        let (values, vectors) = symmetric_eigen(matrix);
+        for (value, vector) in values.iter().zip(vectors) {
+            let vector = [vector.x, vector.y, vector.z];
+            for (row, component) in matrix.iter().zip(vector) {
+                let product = row.iter().zip(vector).map(|(a, b)| a * b).sum::<f64>();
+                assert!((product - value * component).abs() < 1e-9);
+            }
+        }
+        assert!((values.iter().sum::<f64>() - 12.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn principal_axes_of_rotated_cuboid() {
+        let cuboid = Cuboid::from_dim(length!(1 m), length!(2 m), length!(3 m))
+            .rotate_around(Axis::z(), angle!(30 deg))
+            .move_to(Point3D::from_m(1., 2., 3.));
+        let properties = cuboid.mass_properties(Density::from_kg_m3(1000.)).unwrap();
+
+        let moments = properties.principal_moments();
+        assert!((moments[0].kg_m2() - 2500.).abs() < 1e-6);
+        assert!((moments[1].kg_m2() - 5000.).abs() < 1e-6);
+        assert!((moments[2].kg_m2() - 6500.).abs() < 1e-6);
+
+        let axes = properties.principal_axes();
+        let expected_y = Vec3::from((-0.5, 3_f64.sqrt() / 2., 0.));
+        assert!((axes[1].direction.dot(expected_y).abs() - 1.).abs() < 1e-9);
+        assert!((axes[0].direction.z.abs() - 1.).abs() < 1e-9);
+        assert!(
+            (axes[0].origin - Point3D::from_m(1., 2., 3.)).distance_to_origin() < length!(1e-9 m)
+        );
+    }
+
+    #[test]
+    fn moment_of_inertia_of_sphere() {
+        let sphere = Sphere::from_radius(length!(1 m));
+        let properties = sphere.mass_properties(Density::from_kg_m3(1.)).unwrap();
+        let expected = 2. / 5. * properties.mass.kg();
+        for axis in [Axis::x(), Axis::neg_y()] {
+            let moment = properties.moment_of_inertia(&axis).unwrap();
+            assert!((moment.kg_m2() - expected).abs() < 1e-6);
+        }
+        assert!((properties.area.m2() - 4. * core::f64::consts::PI).abs() < 1e-6);
+        assert!(((properties.mass / properties.volume).kg_m3() - 1.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn moment_of_inertia_around_zero_axis() {
+        let properties = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m))
+            .mass_properties(Density::from_kg_m3(1.))
+            .unwrap();
+        let axis = Axis::new(Point3D::origin(), (0., 0., 0.));
+        assert!(matches!(
+            properties.moment_of_inertia(&axis),
+            Err(Error::ZeroVector(_))
+        ));
+    }
+
+    #[test]
+    fn mass_properties_of_empty_part() {
+        assert_eq!(
+            Part::empty().mass_properties(Density::from_kg_m3(1.)),
+            Err(Error::EmptyPart)
+        );
+    }
+}
@@ -1,4 +1,18 @@
+mod cut_depth;
+mod loft;
+mod mass_properties;
 mod part;
+mod path3d;
 pub mod primitives;
+mod stl_options;
+mod sweep;
 
+pub use cut_depth::CutDepth;
+pub use loft::LoftMode;
+pub use mass_properties::MassProperties;
 pub use part::Part;
+pub(crate) use part::{mirror_occt, occt_bounding_box};
+pub use path3d::Path3D;
+pub(crate) use path3d::points_along_edges;
+pub use stl_options::StlOptions;
+pub use sweep::SweepOrientation;
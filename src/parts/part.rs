@@ -9,7 +9,11 @@ use cxx::UniquePtr;
 use opencascade_sys::ffi;
 use tempfile::NamedTempFile;
 
-use crate::{angle, Angle, Axis, Error, Length, Point3D};
+use crate::{
+    angle, sketches::unique_edges, Angle, Area, Axis, CutDepth, Density, Edge3D, EdgeSelector,
+    Error, Face, FaceSelector, Length, LoftMode, MassProperties, MomentOfInertia, Path3D, Plane,
+    Point3D, Sketch, StlOptions, Vec3, Vertex, Volume,
+};
 
 /// A 3D object in space.
 pub struct Part {
@@ -57,6 +61,96 @@ impl Part {
         }
     }
 
+    /// Return a clone of this `Part` with all edges beveled by a distance.
+    ///
+    /// If the `Part` is empty, an `Err(Error::EmptyPart)` is returned. If the distance is not
+    /// positive, an `Err(Error::Chamfer)` is returned. If the chamfer can not be applied (e.g.
+    /// because the distance is larger than an adjacent face), the `Error::Chamfer` is wrapped in
+    /// an `Error::Context` naming the edges.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, Error, length, Part};
+    ///
+    /// let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+    /// let chamfered = cuboid.chamfer(length!(10 cm)).unwrap();
+    /// assert!(chamfered.volume() < cuboid.volume());
+    /// assert_eq!(Part::empty().chamfer(length!(10 cm)), Err(Error::EmptyPart));
+    /// ```
+    pub fn chamfer(&self, distance: Length) -> Result<Self, Error> {
+        match &self.inner {
+            Some(inner) => {
+                let all_edges: Vec<usize> = (0..unique_edges(inner).len()).collect();
+                self.chamfer_edges(distance, &all_edges)
+            }
+            None => Err(Error::EmptyPart),
+        }
+    }
+    /// Return a clone of this `Part` with a selection of edges beveled by a distance.
+    ///
+    /// The edges are selected by their index in the order in which OpenCascade explores the
+    /// topology of the `Part`. Indices that do not belong to an edge are ignored.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, length};
+    ///
+    /// let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+    /// let chamfered = cuboid.chamfer_edges(length!(10 cm), &[0]).unwrap();
+    /// assert!((chamfered.volume() - (1. - 0.5 * 0.1 * 0.1)).abs() < 1e-9);
+    /// ```
+    pub fn chamfer_edges(&self, distance: Length, edges: &[usize]) -> Result<Self, Error> {
+        match &self.inner {
+            Some(inner) => {
+                if distance <= Length::zero() {
+                    return Err(Error::Chamfer(distance));
+                }
+
+                let mut make_chamfer = ffi::BRepFilletAPI_MakeChamfer_ctor(inner);
+                let mut selected = vec![];
+                for (index, edge) in unique_edges(inner).iter().enumerate() {
+                    if edges.contains(&index) {
+                        make_chamfer.pin_mut().add_edge(distance.m(), edge);
+                        selected.push(index);
+                    }
+                }
+                if selected.is_empty() {
+                    return Ok(self.clone());
+                }
+
+                make_chamfer
+                    .pin_mut()
+                    .Build(&ffi::Message_ProgressRange_ctor());
+                if !make_chamfer.IsDone() {
+                    return Err(
+                        Error::Chamfer(distance).context("chamfer", format!("edges {selected:?}"))
+                    );
+                }
+                Ok(Self::from_occt(make_chamfer.pin_mut().Shape()))
+            }
+            None => Err(Error::EmptyPart),
+        }
+    }
+    /// Return a clone of this `Part` with the edges that are chosen by an `EdgeSelector` beveled
+    /// by a distance.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, EdgeSelector, length, Vec3};
+    ///
+    /// let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+    /// let vertical_edges = EdgeSelector::Parallel(Vec3::from((0., 0., 1.)));
+    /// let chamfered = cuboid.chamfer_selected(length!(10 cm), &vertical_edges).unwrap();
+    /// assert!((chamfered.volume() - (1. - 4. * 0.5 * 0.1 * 0.1)).abs() < 1e-9);
+    /// ```
+    pub fn chamfer_selected(
+        &self,
+        distance: Length,
+        selector: &EdgeSelector,
+    ) -> Result<Self, Error> {
+        self.chamfer_edges(distance, &selected_indices(selector.mask(&self.edges())))
+    }
+
     /// Create multiple instances of the `Sketch` spaced evenly around a point.
     ///
     /// # Example
@@ -82,6 +176,272 @@ impl Part {
         }
         new_shape
     }
+    /// Return a clone of this `Part` with a pocket in the shape of a `Sketch` cut into one of its
+    /// faces.
+    ///
+    /// The `Sketch` is placed on the `Plane` of the `Face` (see `Face::plane`) and removed from the
+    /// `Part` down to the given `CutDepth`. If the `Part` is empty, an `Err(Error::EmptyPart)` is
+    /// returned. If a `CutDepth::Blind` is not positive, an `Err(Error::NonPositiveDepth)` is
+    /// returned. Errors of the `Face` (e.g. `Error::NonPlanarFace` for a curved one) and of the
+    /// `Sketch` are wrapped in an `Error::Context` naming them.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, CutDepth, FaceSelector, length, Rectangle, Vec3};
+    ///
+    /// let cuboid = Cuboid::from_dim(length!(2 m), length!(2 m), length!(2 m));
+    /// let top = &FaceSelector::Max(Vec3::from((0., 0., 1.))).select(&cuboid)[0];
+    /// let pocket = Rectangle::from_dim(length!(1 m), length!(1 m));
+    ///
+    /// let blind = cuboid.cut_on_face(&pocket, top, CutDepth::Blind(length!(0.5 m))).unwrap();
+    /// assert!((blind.volume() - 7.5).abs() < 1e-9);
+    ///
+    /// let through = cuboid.cut_on_face(&pocket, top, CutDepth::ThroughAll).unwrap();
+    /// assert!((through.volume() - 6.).abs() < 1e-9);
+    /// ```
+    pub fn cut_on_face(
+        &self,
+        sketch: &Sketch,
+        face: &Face,
+        depth: CutDepth,
+    ) -> Result<Self, Error> {
+        match &self.inner {
+            Some(inner) => {
+                let plane = face
+                    .plane()
+                    .map_err(|err| err.context("cut_on_face", "face"))?;
+                let depth = match depth {
+                    CutDepth::Blind(depth) if depth <= Length::zero() => {
+                        return Err(Error::NonPositiveDepth(depth));
+                    }
+                    CutDepth::Blind(depth) => depth,
+                    CutDepth::ThroughAll => through_all_depth(inner).ok_or(Error::EmptyPart)?,
+                };
+                let tool = sketch
+                    .extrude(&plane, -depth)
+                    .map_err(|err| err.context("cut_on_face", "sketch"))?;
+                Ok(self.subtract(&tool))
+            }
+            None => Err(Error::EmptyPart),
+        }
+    }
+    /// Return the edges of this `Part`.
+    ///
+    /// Every edge occurs only once, even if it is shared by multiple faces. The order of the edges
+    /// matches the indices that are used by `Part::fillet_edges` and `Part::chamfer_edges`. An
+    /// empty `Part` has no edges.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, length, Part};
+    ///
+    /// let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+    /// assert_eq!(cuboid.edges().len(), 12);
+    /// assert!(Part::empty().edges().is_empty());
+    /// ```
+    pub fn edges(&self) -> Vec<Edge3D> {
+        match &self.inner {
+            Some(inner) => unique_edges(inner)
+                .iter()
+                .map(|edge| Edge3D::from_occt(edge))
+                .collect(),
+            None => vec![],
+        }
+    }
+    /// Return the `Part` that is created by extruding a `Sketch` outwards from one of its faces.
+    ///
+    /// The `Sketch` is placed on the `Plane` of the `Face` (see `Face::plane`) and extruded along
+    /// its normal by a height. If the `Part` is empty, an `Err(Error::EmptyPart)` is returned. If
+    /// the height is not positive, an `Err(Error::NonPositiveDepth)` is returned. Errors of the
+    /// `Face` (e.g. `Error::NonPlanarFace` for a curved one) and of the `Sketch` are
+    /// wrapped in an `Error::Context` naming them.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, FaceSelector, length, Rectangle, Vec3};
+    ///
+    /// let cuboid = Cuboid::from_dim(length!(2 m), length!(2 m), length!(2 m));
+    /// let side = &FaceSelector::Max(Vec3::from((1., 0., 0.))).select(&cuboid)[0];
+    /// let boss = Rectangle::from_dim(length!(1 m), length!(1 m));
+    /// let part = cuboid.extrude_on_face(&boss, side, length!(1 m)).unwrap();
+    /// assert!((part.volume() - 9.).abs() < 1e-9);
+    /// ```
+    pub fn extrude_on_face(
+        &self,
+        sketch: &Sketch,
+        face: &Face,
+        height: Length,
+    ) -> Result<Self, Error> {
+        match &self.inner {
+            Some(_) => {
+                if height <= Length::zero() {
+                    return Err(Error::NonPositiveDepth(height));
+                }
+                let plane = face
+                    .plane()
+                    .map_err(|err| err.context("extrude_on_face", "face"))?;
+                let tool = sketch
+                    .extrude(&plane, height)
+                    .map_err(|err| err.context("extrude_on_face", "sketch"))?;
+                Ok(self.add(&tool))
+            }
+            None => Err(Error::EmptyPart),
+        }
+    }
+    /// Return the faces of this `Part`.
+    ///
+    /// Every face occurs only once. An empty `Part` has no faces.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, length, Part};
+    ///
+    /// let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+    /// assert_eq!(cuboid.faces().len(), 6);
+    /// assert!(Part::empty().faces().is_empty());
+    /// ```
+    pub fn faces(&self) -> Vec<Face> {
+        match &self.inner {
+            Some(inner) => {
+                let mut faces: Vec<Face> = vec![];
+                let mut explorer =
+                    ffi::TopExp_Explorer_ctor(inner, ffi::TopAbs_ShapeEnum::TopAbs_FACE);
+                while explorer.More() {
+                    let face = Face::from_occt(ffi::TopoDS_cast_to_face(explorer.Current()));
+                    if !faces.contains(&face) {
+                        faces.push(face);
+                    }
+                    explorer.pin_mut().Next();
+                }
+                faces
+            }
+            None => vec![],
+        }
+    }
+    /// Return a clone of this `Part` with all edges rounded by a radius.
+    ///
+    /// If the `Part` is empty, an `Err(Error::EmptyPart)` is returned. If the radius is not
+    /// positive, an `Err(Error::Fillet)` is returned. If the fillet can not be applied (e.g.
+    /// because the radius is larger than an adjacent face), the `Error::Fillet` is wrapped in an
+    /// `Error::Context` naming the edges.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, Error, length, Part};
+    ///
+    /// let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+    /// let filleted = cuboid.fillet(length!(10 cm)).unwrap();
+    /// assert!(filleted.volume() < cuboid.volume());
+    /// assert_eq!(
+    ///     cuboid.fillet(length!(2 m)).unwrap_err().root_cause(),
+    ///     &Error::Fillet(length!(2 m))
+    /// );
+    /// assert_eq!(Part::empty().fillet(length!(10 cm)), Err(Error::EmptyPart));
+    /// ```
+    pub fn fillet(&self, radius: Length) -> Result<Self, Error> {
+        match &self.inner {
+            Some(inner) => {
+                let all_edges: Vec<usize> = (0..unique_edges(inner).len()).collect();
+                self.fillet_edges(radius, &all_edges)
+            }
+            None => Err(Error::EmptyPart),
+        }
+    }
+    /// Return a clone of this `Part` with a selection of edges rounded by a radius.
+    ///
+    /// The edges are selected by their index in the order in which OpenCascade explores the
+    /// topology of the `Part`. Indices that do not belong to an edge are ignored.
+    ///
+    /// # Example
+    /// ```rust
+    /// use core::f64;
+    /// use anvil::{Cuboid, length};
+    ///
+    /// let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+    /// let filleted = cuboid.fillet_edges(length!(10 cm), &[0]).unwrap();
+    /// assert!((filleted.volume() - (1. - (1. - f64::consts::PI / 4.) * 0.1 * 0.1)).abs() < 1e-6);
+    /// ```
+    pub fn fillet_edges(&self, radius: Length, edges: &[usize]) -> Result<Self, Error> {
+        match &self.inner {
+            Some(inner) => {
+                if radius <= Length::zero() {
+                    return Err(Error::Fillet(radius));
+                }
+
+                let mut make_fillet = ffi::BRepFilletAPI_MakeFillet_ctor(inner);
+                let mut selected = vec![];
+                for (index, edge) in unique_edges(inner).iter().enumerate() {
+                    if edges.contains(&index) {
+                        make_fillet.pin_mut().add_edge(radius.m(), edge);
+                        selected.push(index);
+                    }
+                }
+                if selected.is_empty() {
+                    return Ok(self.clone());
+                }
+
+                make_fillet
+                    .pin_mut()
+                    .Build(&ffi::Message_ProgressRange_ctor());
+                if !make_fillet.IsDone() {
+                    return Err(
+                        Error::Fillet(radius).context("fillet", format!("edges {selected:?}"))
+                    );
+                }
+                Ok(Self::from_occt(make_fillet.pin_mut().Shape()))
+            }
+            None => Err(Error::EmptyPart),
+        }
+    }
+    /// Return a clone of this `Part` with the edges that are chosen by an `EdgeSelector` rounded by
+    /// a radius.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, EdgeSelector, length, Vec3};
+    ///
+    /// let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+    /// let top_edges = EdgeSelector::Max(Vec3::from((0., 0., 1.)));
+    /// let filleted = cuboid.fillet_selected(length!(10 cm), &top_edges).unwrap();
+    /// assert!(filleted.volume() < cuboid.volume());
+    /// ```
+    pub fn fillet_selected(&self, radius: Length, selector: &EdgeSelector) -> Result<Self, Error> {
+        self.fillet_edges(radius, &selected_indices(selector.mask(&self.edges())))
+    }
+    /// Create a rectangular grid of instances of this `Part` that is aligned with a `Plane`.
+    ///
+    /// The instances are spaced along the x- and y-axis of the `Plane`, starting from the current
+    /// position of the `Part`. A count of zero or one along an axis results in no repetition along
+    /// it.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, length, Plane, point};
+    ///
+    /// let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+    /// assert_eq!(
+    ///     cuboid.grid_pattern(&Plane::xy(), length!(2 m), length!(3 m), 2, 2),
+    ///     cuboid
+    ///         .add(&cuboid.move_to(point!(2 m, 0 m, 0 m)))
+    ///         .add(&cuboid.move_to(point!(0 m, 3 m, 0 m)))
+    ///         .add(&cuboid.move_to(point!(2 m, 3 m, 0 m)))
+    /// )
+    /// ```
+    pub fn grid_pattern(
+        &self,
+        plane: &Plane,
+        x_spacing: Length,
+        y_spacing: Length,
+        x_count: usize,
+        y_count: usize,
+    ) -> Self {
+        let row = (1..x_count).fold(self.clone(), |pattern, instance| {
+            pattern.add(&self.translate(plane.x_axis() * (x_spacing * instance as f64)))
+        });
+        (1..y_count).fold(row.clone(), |pattern, instance| {
+            pattern.add(&row.translate(plane.y_axis() * (y_spacing * instance as f64)))
+        })
+    }
     /// Return the `Part` that is created from the overlapping volume between this one and another.
     ///
     /// # Example
@@ -103,6 +463,124 @@ impl Part {
             _ => Part { inner: None },
         }
     }
+    /// Create multiple instances of this `Part` spaced evenly along a direction.
+    ///
+    /// The first instance stays at the current position of the `Part` and every following one is
+    /// moved by the spacing along the direction. If the direction has no length, an
+    /// `Err(Error::ZeroVector)` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, length, point, Vec3};
+    ///
+    /// let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+    /// assert_eq!(
+    ///     cuboid.linear_pattern(Vec3::from((1., 0., 0.)), length!(2 m), 3),
+    ///     Ok(cuboid
+    ///         .add(&cuboid.move_to(point!(2 m, 0 m, 0 m)))
+    ///         .add(&cuboid.move_to(point!(4 m, 0 m, 0 m))))
+    /// )
+    /// ```
+    pub fn linear_pattern(
+        &self,
+        direction: Vec3,
+        spacing: Length,
+        count: usize,
+    ) -> Result<Self, Error> {
+        let direction = direction.normalize()?;
+        Ok((1..count).fold(self.clone(), |pattern, instance| {
+            pattern.add(&self.translate(direction * (spacing * instance as f64)))
+        }))
+    }
+    /// Construct a `Part` by connecting a series of `Sketch`es on different `Plane`s.
+    ///
+    /// The resulting solid passes through the outer boundary of every profile in the given order.
+    /// If fewer than two profiles are given, an `Err(Error::NotEnoughProfiles)` is returned. If one
+    /// of the profiles is empty, the `Error::EmptySketch` is wrapped in an `Error::Context` naming
+    /// the profile. If two consecutive profiles lie on the same plane, an
+    /// `Err(Error::CoplanarSketches)` is returned. If the profiles can not be connected, for
+    /// example because their boundaries are incompatible, an `Error::Loft` is wrapped in an
+    /// `Error::Context`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, length, LoftMode, Part, Plane, Point3D, Rectangle};
+    ///
+    /// let top_plane = Plane::new(Point3D::from_m(0., 0., 1.), (1., 0., 0.), (0., 1., 0.)).unwrap();
+    /// let part = Part::loft(
+    ///     &[
+    ///         (Rectangle::from_dim(length!(1 m), length!(1 m)), Plane::xy()),
+    ///         (Rectangle::from_dim(length!(1 m), length!(1 m)), top_plane),
+    ///     ],
+    ///     LoftMode::Ruled,
+    /// );
+    /// assert_eq!(
+    ///     part,
+    ///     Ok(Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m)).move_to(Point3D::from_m(0., 0., 0.5)))
+    /// );
+    /// ```
+    pub fn loft(profiles: &[(Sketch, Plane)], mode: LoftMode) -> Result<Self, Error> {
+        if profiles.len() < 2 {
+            return Err(Error::NotEnoughProfiles(profiles.len()));
+        }
+        for pair in profiles.windows(2) {
+            if pair[0].1.is_coplanar_with(&pair[1].1) {
+                return Err(Error::CoplanarSketches);
+            }
+        }
+
+        let mut make_loft = ffi::BRepOffsetAPI_ThruSections_ctor(true, mode == LoftMode::Ruled);
+        for (index, (sketch, plane)) in profiles.iter().enumerate() {
+            let wire = sketch
+                .to_occt_wire(plane)
+                .map_err(|err| err.context("loft", format!("profile {index}")))?;
+            make_loft.pin_mut().AddWire(&wire);
+        }
+
+        make_loft
+            .pin_mut()
+            .Build(&ffi::Message_ProgressRange_ctor());
+        if !make_loft.IsDone() {
+            return Err(Error::Loft.context("loft", "profiles"));
+        }
+        Ok(Self::from_occt(make_loft.pin_mut().Shape()))
+    }
+    /// Return a mirror image of this `Part` across a `Plane`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, Plane, point};
+    ///
+    /// let cuboid = Cuboid::from_corners(point!(1 m, 0 m, 0 m), point!(2 m, 1 m, 1 m));
+    /// assert_eq!(
+    ///     cuboid.mirror(&Plane::yz()),
+    ///     Cuboid::from_corners(point!(-2 m, 0 m, 0 m), point!(-1 m, 1 m, 1 m))
+    /// )
+    /// ```
+    pub fn mirror(&self, plane: &Plane) -> Self {
+        match &self.inner {
+            Some(inner) => Self {
+                inner: Some(mirror_occt(inner, plane.origin(), plane.normal())),
+            },
+            None => Self { inner: None },
+        }
+    }
+    /// Return the `Part` that is created by merging this one with its mirror image across a
+    /// `Plane`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, Plane, point};
+    ///
+    /// let cuboid = Cuboid::from_corners(point!(0 m, 0 m, 0 m), point!(1 m, 1 m, 1 m));
+    /// assert_eq!(
+    ///     cuboid.mirror_and_add(&Plane::yz()),
+    ///     Cuboid::from_corners(point!(-1 m, 0 m, 0 m), point!(1 m, 1 m, 1 m))
+    /// )
+    /// ```
+    pub fn mirror_and_add(&self, plane: &Plane) -> Self {
+        self.add(&self.mirror(plane))
+    }
     /// Return a clone of this `Part` with the center moved to a specified point.
     ///
     /// # Example
@@ -126,6 +604,38 @@ impl Part {
             None => Self { inner: None },
         }
     }
+    /// Create multiple instances of this `Part` spaced evenly along a `Path3D`.
+    ///
+    /// The first instance stays at the current position of the `Part`. Every following one is moved
+    /// by the offset between the start of the path and a point further along it. On an open path,
+    /// the last instance is placed at the end of the path; on a closed path, the instances are
+    /// spread around the whole loop. If the path is empty, an `Err(Error::EmptyPath)` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, length, Path3D, point};
+    ///
+    /// let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+    /// let path = Path3D::at(point!(0 m, 0 m, 0 m))
+    ///     .line_to(point!(2 m, 0 m, 0 m))
+    ///     .line_to(point!(2 m, 0 m, 2 m));
+    /// assert_eq!(
+    ///     cuboid.pattern_along(&path, 3),
+    ///     Ok(cuboid
+    ///         .add(&cuboid.move_to(point!(2 m, 0 m, 0 m)))
+    ///         .add(&cuboid.move_to(point!(2 m, 0 m, 2 m))))
+    /// )
+    /// ```
+    pub fn pattern_along(&self, path: &Path3D, count: usize) -> Result<Self, Error> {
+        let start = path.start();
+        Ok(path
+            .points_along(count)?
+            .into_iter()
+            .skip(1)
+            .fold(self.clone(), |pattern, point| {
+                pattern.add(&self.translate(point - start))
+            }))
+    }
     /// Return a clone of this `Part` rotated around an `Axis`.
     ///
     /// For positive angles, the right-hand-rule applies for the direction of rotation.
@@ -179,6 +689,63 @@ impl Part {
             None => Self { inner: None },
         }
     }
+    /// Return a clone of this `Part` that is hollowed out with a uniform wall thickness.
+    ///
+    /// The walls are created on the inside of the `Part`, so its outer dimensions stay the same.
+    /// Faces that are chosen by any of the given `FaceSelector`s are removed to leave the `Part`
+    /// open on that side. If the `Part` is empty, an `Err(Error::EmptyPart)` is
+    /// returned. If the thickness is not positive, an `Err(Error::Shell)` is returned. If the shell
+    /// can not be constructed, the `Error::Shell` is wrapped in an `Error::Context` naming the open
+    /// faces.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, FaceSelector, length, Vec3};
+    ///
+    /// let cuboid = Cuboid::from_dim(length!(2 m), length!(2 m), length!(2 m));
+    /// let top = FaceSelector::Max(Vec3::from((0., 0., 1.)));
+    /// let open_box = cuboid.shell(length!(0.5 m), &[top]).unwrap();
+    /// assert!((open_box.volume() - (8. - 1.5)).abs() < 1e-6);
+    /// ```
+    pub fn shell(&self, thickness: Length, open_faces: &[FaceSelector]) -> Result<Self, Error> {
+        match &self.inner {
+            Some(inner) => {
+                if thickness <= Length::zero() {
+                    return Err(Error::Shell(thickness));
+                }
+                let faces = self.faces();
+                let masks: Vec<Vec<bool>> = open_faces
+                    .iter()
+                    .map(|selector| selector.mask(&faces))
+                    .collect();
+
+                let mut faces_to_remove = ffi::new_list_of_shape();
+                let mut removed = vec![];
+                for (index, face) in faces.iter().enumerate() {
+                    if masks.iter().any(|mask| mask[index]) {
+                        ffi::shape_list_append_face(faces_to_remove.pin_mut(), &face.inner);
+                        removed.push(index);
+                    }
+                }
+
+                let mut make_thick_solid = ffi::BRepOffsetAPI_MakeThickSolid_ctor();
+                ffi::MakeThickSolidByJoin(
+                    make_thick_solid.pin_mut(),
+                    inner,
+                    &faces_to_remove,
+                    -thickness.m(),
+                    1e-6,
+                );
+                if !make_thick_solid.IsDone() {
+                    return Err(
+                        Error::Shell(thickness).context("shell", format!("open faces {removed:?}"))
+                    );
+                }
+                Ok(Self::from_occt(make_thick_solid.pin_mut().Shape()))
+            }
+            None => Err(Error::EmptyPart),
+        }
+    }
     /// Return a copy of this `Part` with the intersection of another removed.
     ///
     /// # Example
@@ -205,6 +772,37 @@ impl Part {
             (None, _) => Part { inner: None },
         }
     }
+    /// Return the vertices of this `Part`.
+    ///
+    /// Every vertex occurs only once, even if it is shared by multiple edges. An empty `Part` has
+    /// no vertices.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, length, Part};
+    ///
+    /// let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+    /// assert_eq!(cuboid.vertices().len(), 8);
+    /// assert!(Part::empty().vertices().is_empty());
+    /// ```
+    pub fn vertices(&self) -> Vec<Vertex> {
+        match &self.inner {
+            Some(inner) => {
+                let mut vertices: Vec<Vertex> = vec![];
+                let mut explorer =
+                    ffi::TopExp_Explorer_ctor(inner, ffi::TopAbs_ShapeEnum::TopAbs_VERTEX);
+                while explorer.More() {
+                    let vertex = Vertex::from_occt(ffi::TopoDS_cast_to_vertex(explorer.Current()));
+                    if !vertices.contains(&vertex) {
+                        vertices.push(vertex);
+                    }
+                    explorer.pin_mut().Next();
+                }
+                vertices
+            }
+            None => vec![],
+        }
+    }
 
     /// Return the volume occupied by this `Part` in cubic meters.
     ///
@@ -260,35 +858,288 @@ impl Part {
             None => Err(Error::EmptyPart),
         }
     }
-
-    /// Write the `Part` to a file in the STEP format.
-    pub fn write_step(&self, path: impl AsRef<Path>) -> Result<(), Error> {
-        match &self.scale(1000.).inner {
-            Some(inner) => {
-                let mut writer = ffi::STEPControl_Writer_ctor();
-                let status = ffi::transfer_shape(writer.pin_mut(), inner);
-                if status != ffi::IFSelect_ReturnStatus::IFSelect_RetDone {
-                    return Err(Error::StepWrite(path.as_ref().to_path_buf()));
-                }
-                let status = ffi::write_step(
-                    writer.pin_mut(),
-                    path.as_ref().to_string_lossy().to_string(),
-                );
-                if status != ffi::IFSelect_ReturnStatus::IFSelect_RetDone {
-                    return Err(Error::StepWrite(path.as_ref().to_path_buf()));
-                }
-            }
-            None => return Err(Error::EmptyPart),
-        }
-        Ok(())
-    }
-
-    /// Write the `Part` to a file in the STL format.
+    /// Return the corners with the smallest and largest coordinates of the tightest axis-aligned
+    /// box that encloses this `Part`.
+    ///
+    /// The box is computed from the exact geometry of the `Part`. If a quick estimate is
+    /// sufficient, `Part::loose_bounding_box` is faster. If the `Part` is empty, an
+    /// `Err(Error::EmptyPart)` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, length, Point3D};
+    ///
+    /// let cuboid = Cuboid::from_corners(Point3D::from_m(0., 0., 0.), Point3D::from_m(1., 2., 3.));
+    /// let (min, max) = cuboid.bounding_box().unwrap();
+    /// assert!((min - Point3D::from_m(0., 0., 0.)).distance_to_origin() < length!(1e-6 m));
+    /// assert!((max - Point3D::from_m(1., 2., 3.)).distance_to_origin() < length!(1e-6 m));
+    /// ```
+    pub fn bounding_box(&self) -> Result<(Point3D, Point3D), Error> {
+        match &self.inner {
+            Some(inner) => occt_bounding_box(inner, true).ok_or(Error::EmptyPart),
+            None => Err(Error::EmptyPart),
+        }
+    }
+    /// Return the corners with the smallest and largest coordinates of an axis-aligned box that
+    /// encloses this `Part`.
+    ///
+    /// The box is computed quickly from the triangulation and tolerances of the `Part`, which
+    /// means that it may be slightly larger than `Part::bounding_box`. If the `Part` is empty, an
+    /// `Err(Error::EmptyPart)` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{length, Sphere};
+    ///
+    /// let sphere = Sphere::from_radius(length!(1 m));
+    /// let (min, max) = sphere.loose_bounding_box().unwrap();
+    /// assert!(min.x <= length!(-1 m) && max.x >= length!(1 m));
+    /// ```
+    pub fn loose_bounding_box(&self) -> Result<(Point3D, Point3D), Error> {
+        match &self.inner {
+            Some(inner) => occt_bounding_box(inner, false).ok_or(Error::EmptyPart),
+            None => Err(Error::EmptyPart),
+        }
+    }
+    /// Return the `MassProperties` of this `Part` for a uniform `Density`.
+    ///
+    /// If the `Part` is empty, an `Err(Error::EmptyPart)` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, Density, length};
+    ///
+    /// let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+    /// let properties = cuboid.mass_properties(Density::from_kg_m3(7850.)).unwrap();
+    /// assert!((properties.mass.kg() - 7850.).abs() < 1e-6);
+    /// assert!((properties.area.m2() - 6.).abs() < 1e-9);
+    /// ```
+    pub fn mass_properties(&self, density: Density) -> Result<MassProperties, Error> {
+        match &self.inner {
+            Some(inner) => {
+                let mut surface_gprops = ffi::GProp_GProps_ctor();
+                ffi::BRepGProp_SurfaceProperties(inner, surface_gprops.pin_mut());
+
+                let mut volume_gprops = ffi::GProp_GProps_ctor();
+                ffi::BRepGProp_VolumeProperties(inner, volume_gprops.pin_mut());
+                let centre_of_mass = ffi::GProp_GProps_CentreOfMass(&volume_gprops);
+                let matrix_of_inertia = ffi::GProp_GProps_MatrixOfInertia(&volume_gprops);
+
+                let mut inertia = [[MomentOfInertia::zero(); 3]; 3];
+                for (row, entries) in inertia.iter_mut().enumerate() {
+                    for (col, entry) in entries.iter_mut().enumerate() {
+                        *entry = MomentOfInertia::from_kg_m2(
+                            density.kg_m3()
+                                * matrix_of_inertia.Value(row as i32 + 1, col as i32 + 1),
+                        );
+                    }
+                }
+
+                let volume = Volume::from_m3(volume_gprops.Mass());
+                Ok(MassProperties {
+                    area: Area::from_m2(surface_gprops.Mass()),
+                    center: Point3D::from_m(
+                        centre_of_mass.X(),
+                        centre_of_mass.Y(),
+                        centre_of_mass.Z(),
+                    ),
+                    density,
+                    inertia,
+                    mass: density * volume,
+                    volume,
+                })
+            }
+            None => Err(Error::EmptyPart),
+        }
+    }
+
+    /// Read a `Part` from a file in the STL format.
+    ///
+    /// Both ASCII and binary files are supported. The values in the file are interpreted as
+    /// meters, mirroring `Part::write_stl`. The triangles are sewn into a closed shell which is
+    /// converted into a solid, so that the `Part` can be used in boolean operations.
+    ///
+    /// If the file can not be read, an `Error::StlRead` is returned. If the mesh has holes and
+    /// therefore does not enclose a volume, an `Error::NotWatertight` is returned. Both are
+    /// wrapped in an `Error::Context` naming the file.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::path::PathBuf;
+    /// use anvil::{Error, Part};
+    ///
+    /// assert_eq!(
+    ///     Part::read_stl("does/not/exist.stl").unwrap_err().root_cause(),
+    ///     &Error::StlRead(PathBuf::from("does/not/exist.stl"))
+    /// );
+    /// ```
+    pub fn read_stl(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let with_context =
+            |err: Error| err.context("read_stl", path.as_ref().display().to_string());
+
+        let mut reader = ffi::StlAPI_Reader_ctor();
+        let mut mesh = ffi::TopoDS_Shape_ctor();
+        let success = ffi::read_stl(
+            reader.pin_mut(),
+            mesh.pin_mut(),
+            path.as_ref().to_string_lossy().to_string(),
+        );
+        if !success || mesh.IsNull() {
+            return Err(with_context(Error::StlRead(path.as_ref().to_path_buf())));
+        }
+
+        let mut sewing = ffi::BRepBuilderAPI_Sewing_ctor(1e-6);
+        sewing.pin_mut().Add(&mesh);
+        sewing.pin_mut().Perform(&ffi::Message_ProgressRange_ctor());
+        let sewed_shape = sewing.SewedShape();
+
+        let mut make_solid = ffi::BRepBuilderAPI_MakeSolid_ctor();
+        let mut n_shells = 0;
+        let mut explorer =
+            ffi::TopExp_Explorer_ctor(sewed_shape, ffi::TopAbs_ShapeEnum::TopAbs_SHELL);
+        while explorer.More() {
+            let shell = explorer.Current();
+            if !ffi::BRep_Tool_IsClosed(shell) {
+                return Err(with_context(Error::NotWatertight(
+                    path.as_ref().to_path_buf(),
+                )));
+            }
+            make_solid
+                .pin_mut()
+                .add_shell(ffi::TopoDS_cast_to_shell(shell));
+            n_shells += 1;
+            explorer.pin_mut().Next();
+        }
+        if n_shells == 0 {
+            return Err(with_context(Error::NotWatertight(
+                path.as_ref().to_path_buf(),
+            )));
+        }
+
+        // the winding of the triangles decides whether the shells face outwards
+        let mut solid = ffi::TopoDS_Solid_to_owned(make_solid.pin_mut().Solid());
+        if !ffi::BRepLib_OrientClosedSolid(solid.pin_mut()) {
+            return Err(with_context(Error::NotWatertight(
+                path.as_ref().to_path_buf(),
+            )));
+        }
+
+        Ok(Self::from_occt(ffi::cast_solid_to_shape(&solid)))
+    }
+    /// Read a `Part` from a file in the STEP format.
+    ///
+    /// The values in the file are interpreted as millimeters, mirroring `Part::write_step`. If the
+    /// file can not be read or does not contain a shape, an `Error::StepRead` is returned, wrapped
+    /// in an `Error::Context` naming the file.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::path::PathBuf;
+    /// use anvil::{Error, Part};
+    ///
+    /// assert_eq!(
+    ///     Part::read_step("does/not/exist.step").unwrap_err().root_cause(),
+    ///     &Error::StepRead(PathBuf::from("does/not/exist.step"))
+    /// );
+    /// ```
+    pub fn read_step(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let error = || {
+            Error::StepRead(path.as_ref().to_path_buf())
+                .context("read_step", path.as_ref().display().to_string())
+        };
+
+        let mut reader = ffi::STEPControl_Reader_ctor();
+        let status = ffi::read_step(
+            reader.pin_mut(),
+            path.as_ref().to_string_lossy().to_string(),
+        );
+        if status != ffi::IFSelect_ReturnStatus::IFSelect_RetDone {
+            return Err(error());
+        }
+
+        reader
+            .pin_mut()
+            .TransferRoots(&ffi::Message_ProgressRange_ctor());
+        let shape = ffi::one_shape(&reader);
+        if shape.IsNull() {
+            return Err(error());
+        }
+
+        Ok(Self::from_occt(&scale_around_origin(&shape, 0.001)))
+    }
+
+    /// Write the `Part` to a file in the STEP format.
+    pub fn write_step(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        match &self.inner {
+            Some(inner) => {
+                let mut writer = ffi::STEPControl_Writer_ctor();
+                let status =
+                    ffi::transfer_shape(writer.pin_mut(), &scale_around_origin(inner, 1000.));
+                if status != ffi::IFSelect_ReturnStatus::IFSelect_RetDone {
+                    return Err(Error::StepWrite(path.as_ref().to_path_buf()));
+                }
+                let status = ffi::write_step(
+                    writer.pin_mut(),
+                    path.as_ref().to_string_lossy().to_string(),
+                );
+                if status != ffi::IFSelect_ReturnStatus::IFSelect_RetDone {
+                    return Err(Error::StepWrite(path.as_ref().to_path_buf()));
+                }
+            }
+            None => return Err(Error::EmptyPart),
+        }
+        Ok(())
+    }
+
+    /// Write the `Part` to a file in the STL format.
+    ///
+    /// The default `StlOptions` are used, which produce an ASCII file in meters.
     pub fn write_stl(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        self.write_stl_with_options(path, &StlOptions::default())
+    }
+    /// Write the `Part` to a file in the STL format using custom `StlOptions`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, length, StlOptions};
+    /// use tempfile::NamedTempFile;
+    ///
+    /// let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+    /// let file = NamedTempFile::with_suffix(".stl").unwrap();
+    /// let path = file.path();
+    /// let options = StlOptions {
+    ///     binary: true,
+    ///     unit: length!(1 mm),
+    ///     ..Default::default()
+    /// };
+    /// assert!(cuboid.write_stl_with_options(path, &options).is_ok());
+    /// ```
+    pub fn write_stl_with_options(
+        &self,
+        path: impl AsRef<Path>,
+        options: &StlOptions,
+    ) -> Result<(), Error> {
         match &self.inner {
             Some(inner) => {
+                if options.unit <= Length::zero() {
+                    return Err(Error::StlWrite(path.as_ref().to_path_buf()));
+                }
+
+                let mut transform = ffi::new_transform();
+                transform
+                    .pin_mut()
+                    .SetScale(&Point3D::origin().to_occt_point(), 1. / options.unit.m());
+                let mut operation = ffi::BRepBuilderAPI_Transform_ctor(inner, &transform, false);
+                let scaled_shape = operation.pin_mut().Shape();
+
+                let mesh = ffi::BRepMesh_IncrementalMesh_ctor_with_angle(
+                    scaled_shape,
+                    options.linear_deflection / options.unit,
+                    options.angular_deflection.rad(),
+                );
+
                 let mut writer = ffi::StlAPI_Writer_ctor();
-                let mesh = ffi::BRepMesh_IncrementalMesh_ctor(inner, 0.0001);
+                ffi::StlAPI_Writer_set_ascii_mode(writer.pin_mut(), !options.binary);
                 let success = ffi::write_stl(
                     writer.pin_mut(),
                     mesh.Shape(),
@@ -305,12 +1156,36 @@ impl Part {
     }
     /// Return the STL lines that describe this `Part`.
     pub fn stl(&self) -> Result<Vec<String>, Error> {
+        self.stl_with_options(&StlOptions::default())
+    }
+    /// Return the STL lines that describe this `Part` using custom `StlOptions`.
+    ///
+    /// As the lines of a file are returned, the ASCII format is always used, regardless of
+    /// `StlOptions::binary`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{angle, length, Sphere, StlOptions};
+    ///
+    /// let sphere = Sphere::from_radius(length!(1 m));
+    /// let coarse = StlOptions {
+    ///     linear_deflection: length!(10 cm),
+    ///     angular_deflection: angle!(45 deg),
+    ///     ..Default::default()
+    /// };
+    /// assert!(sphere.stl_with_options(&coarse).unwrap().len() < sphere.stl().unwrap().len());
+    /// ```
+    pub fn stl_with_options(&self, options: &StlOptions) -> Result<Vec<String>, Error> {
         match &self.inner {
             Some(_) => {
                 let temp_file = NamedTempFile::new().expect("could not create tempfile");
                 let path = temp_file.path();
 
-                self.write_stl(path)?;
+                let ascii_options = StlOptions {
+                    binary: false,
+                    ..*options
+                };
+                self.write_stl_with_options(path, &ascii_options)?;
 
                 let file = fs::File::open(path).map_err(|_| Error::StlWrite(path.into()))?;
                 let lines = io::BufReader::new(file)
@@ -328,6 +1203,21 @@ impl Part {
         let inner = ffi::TopoDS_Shape_to_owned(part);
         Self { inner: Some(inner) }
     }
+
+    /// Return a clone of this `Part` moved by an offset.
+    fn translate(&self, offset: Point3D) -> Self {
+        match &self.inner {
+            Some(inner) => {
+                let mut transform = ffi::new_transform();
+                transform
+                    .pin_mut()
+                    .set_translation_vec(&offset.to_occt_vec());
+                let mut operation = ffi::BRepBuilderAPI_Transform_ctor(inner, &transform, false);
+                Self::from_occt(operation.pin_mut().Shape())
+            }
+            None => Self { inner: None },
+        }
+    }
 }
 
 impl Clone for Part {
@@ -363,6 +1253,88 @@ impl Debug for Part {
     }
 }
 
+/// Return a copy of an OpenCascade shape that is scaled by a factor around the origin.
+fn scale_around_origin(shape: &ffi::TopoDS_Shape, factor: f64) -> UniquePtr<ffi::TopoDS_Shape> {
+    let mut transform = ffi::new_transform();
+    transform
+        .pin_mut()
+        .SetScale(&Point3D::origin().to_occt_point(), factor);
+    let mut operation = ffi::BRepBuilderAPI_Transform_ctor(shape, &transform, false);
+    ffi::TopoDS_Shape_to_owned(operation.pin_mut().Shape())
+}
+
+/// Return a length that exceeds the distance between any two points of an OpenCascade shape.
+///
+/// If the shape contains no geometry, `None` is returned.
+fn through_all_depth(shape: &ffi::TopoDS_Shape) -> Option<Length> {
+    let (min, max) = occt_bounding_box(shape, false)?;
+    Some((max - min).distance_to_origin() * 1.1)
+}
+
+/// Return the minimum and maximum corners of the axis-aligned bounding box of an OpenCascade
+/// shape.
+///
+/// An optimal box is computed from the exact geometry, otherwise the box is computed from the
+/// triangulation of the shape and enlarged by its tolerances. If the shape contains no geometry,
+/// the box is void and `None` is returned.
+pub(crate) fn occt_bounding_box(
+    shape: &ffi::TopoDS_Shape,
+    optimal: bool,
+) -> Option<(Point3D, Point3D)> {
+    let mut bounding_box = ffi::Bnd_Box_ctor();
+    if optimal {
+        ffi::BRepBndLib_AddOptimal(shape, bounding_box.pin_mut(), false, false);
+    } else {
+        ffi::BRepBndLib_Add(shape, bounding_box.pin_mut(), true);
+    }
+    if bounding_box.IsVoid() {
+        return None;
+    }
+    let min = ffi::Bnd_Box_CornerMin(&bounding_box);
+    let max = ffi::Bnd_Box_CornerMax(&bounding_box);
+    Some((
+        Point3D::from_m(min.X(), min.Y(), min.Z()),
+        Point3D::from_m(max.X(), max.Y(), max.Z()),
+    ))
+}
+
+/// Return the indices of the entries that are set in a selection mask.
+fn selected_indices(mask: Vec<bool>) -> Vec<usize> {
+    mask.into_iter()
+        .enumerate()
+        .filter(|(_, selected)| *selected)
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Return the mirror image of an OpenCascade shape across the plane through a point with a
+/// normal.
+///
+/// The mirror is composed of a point reflection through the origin of the plane followed by a half
+/// turn around its normal, which leaves the orientation of the mirrored faces consistent.
+pub(crate) fn mirror_occt(
+    shape: &ffi::TopoDS_Shape,
+    origin: Point3D,
+    normal: Vec3,
+) -> UniquePtr<ffi::TopoDS_Shape> {
+    let mut reflection = ffi::new_transform();
+    reflection.pin_mut().SetScale(&origin.to_occt_point(), -1.);
+    let mut operation = ffi::BRepBuilderAPI_Transform_ctor(shape, &reflection, true);
+    let reflected = ffi::TopoDS_Shape_to_owned(operation.pin_mut().Shape());
+
+    let mut half_turn = ffi::new_transform();
+    half_turn.pin_mut().SetRotation(
+        &Axis {
+            origin,
+            direction: normal,
+        }
+        .to_occt_ax1(),
+        std::f64::consts::PI,
+    );
+    let mut operation = ffi::BRepBuilderAPI_Transform_ctor(&reflected, &half_turn, false);
+    ffi::TopoDS_Shape_to_owned(operation.pin_mut().Shape())
+}
+
 fn round(x: f64, n_digits: u8) -> f64 {
     (x * f64::from(10 ^ n_digits)).round() / f64::from(10 ^ n_digits)
 }
@@ -370,7 +1342,8 @@ fn round(x: f64, n_digits: u8) -> f64 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{length, Cuboid, Point3D, Sphere};
+    use crate::{length, Circle, Cuboid, CurveType, Cylinder, Point3D, Rectangle, Sphere};
+    use tempdir::TempDir;
 
     #[test]
     fn eq_both_none() {
@@ -441,6 +1414,549 @@ mod tests {
             .is_ok());
     }
 
+    #[test]
+    fn fillet_empty() {
+        assert_eq!(Part::empty().fillet(length!(1 m)), Err(Error::EmptyPart))
+    }
+
+    #[test]
+    fn fillet_negative_radius() {
+        let cuboid = Cuboid::from_m(1., 1., 1.);
+        assert_eq!(
+            cuboid.fillet(length!(-1 m)),
+            Err(Error::Fillet(length!(-1 m)))
+        )
+    }
+
+    #[test]
+    fn fillet_edges_none_selected() {
+        let cuboid = Cuboid::from_m(1., 1., 1.);
+        assert_eq!(cuboid.fillet_edges(length!(10 cm), &[]), Ok(cuboid))
+    }
+
+    #[test]
+    fn chamfer_too_large_distance() {
+        let cuboid = Cuboid::from_m(1., 1., 1.);
+        assert_eq!(
+            cuboid.chamfer(length!(2 m)),
+            Err(Error::Chamfer(length!(2 m)).context(
+                "chamfer",
+                format!("edges {:?}", (0..12).collect::<Vec<_>>())
+            ))
+        )
+    }
+
+    #[test]
+    fn loft_no_profiles() {
+        assert_eq!(
+            Part::loft(&[], LoftMode::Smooth),
+            Err(Error::NotEnoughProfiles(0))
+        )
+    }
+
+    #[test]
+    fn loft_single_profile() {
+        let profile = (Rectangle::from_dim(length!(1 m), length!(1 m)), Plane::xy());
+        assert_eq!(
+            Part::loft(&[profile], LoftMode::Ruled),
+            Err(Error::NotEnoughProfiles(1))
+        )
+    }
+
+    #[test]
+    fn loft_empty_profile() {
+        let top_plane = Plane::new(Point3D::from_m(0, 0, 1), (1., 0., 0.), (0., 1., 0.)).unwrap();
+        assert_eq!(
+            Part::loft(
+                &[
+                    (Rectangle::from_m(1, 1), Plane::xy()),
+                    (Sketch::empty(), top_plane)
+                ],
+                LoftMode::Smooth
+            ),
+            Err(Error::EmptySketch.context("loft", "profile 1"))
+        )
+    }
+
+    #[test]
+    fn loft_coplanar_profiles() {
+        assert_eq!(
+            Part::loft(
+                &[
+                    (Rectangle::from_m(1, 1), Plane::xy()),
+                    (Circle::from_radius(length!(1 m)), Plane::xy())
+                ],
+                LoftMode::Smooth
+            ),
+            Err(Error::CoplanarSketches)
+        )
+    }
+
+    #[test]
+    fn loft_circles_to_cylinder() {
+        let top_plane = Plane::new(Point3D::from_m(0, 0, 2), (1., 0., 0.), (0., 1., 0.)).unwrap();
+        assert_eq!(
+            Part::loft(
+                &[
+                    (Circle::from_radius(length!(1 m)), Plane::xy()),
+                    (Circle::from_radius(length!(1 m)), top_plane)
+                ],
+                LoftMode::Smooth
+            ),
+            Ok(Cylinder::from_radius(length!(1 m), length!(2 m)).move_to(Point3D::from_m(0, 0, 1)))
+        )
+    }
+
+    #[test]
+    fn linear_pattern_single_instance() {
+        let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+        let direction = Vec3::from((0., 1., 0.));
+        assert_eq!(
+            cuboid.linear_pattern(direction, length!(2 m), 0),
+            Ok(cuboid.clone())
+        );
+        assert_eq!(
+            cuboid.linear_pattern(direction, length!(2 m), 1),
+            Ok(cuboid.clone())
+        );
+    }
+
+    #[test]
+    fn linear_pattern_zero_direction() {
+        let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+        assert_eq!(
+            cuboid.linear_pattern(Vec3::from((0., 0., 0.)), length!(2 m), 3),
+            Err(Error::ZeroVector(Vec3::from((0., 0., 0.))))
+        );
+    }
+
+    #[test]
+    fn grid_pattern_volume() {
+        let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+        let grid = cuboid.grid_pattern(&Plane::xz(), length!(2 m), length!(2 m), 3, 2);
+        assert!((grid.volume() - 6. * cuboid.volume()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn pattern_along_empty_path() {
+        let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+        assert_eq!(
+            cuboid.pattern_along(&Path3D::at(Point3D::origin()), 3),
+            Err(Error::EmptyPath)
+        );
+    }
+
+    #[test]
+    fn mirror_keeps_volume() {
+        let cuboid = Cuboid::from_corners(Point3D::from_m(1, 1, 1), Point3D::from_m(2, 3, 4));
+        let plane = Plane::new(Point3D::origin(), (1., 1., 0.), (0., 0., 1.)).unwrap();
+        assert!((cuboid.mirror(&plane).volume() - cuboid.volume()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn mirror_twice_is_identity() {
+        let cuboid = Cuboid::from_corners(Point3D::from_m(1, 1, 1), Point3D::from_m(2, 3, 4));
+        assert_eq!(cuboid.mirror(&Plane::xy()).mirror(&Plane::xy()), cuboid);
+    }
+
+    #[test]
+    fn mirror_empty() {
+        assert_eq!(Part::empty().mirror(&Plane::xz()), Part::empty());
+    }
+
+    #[test]
+    fn shell_closed() {
+        let cuboid = Cuboid::from_dim(length!(2 m), length!(2 m), length!(2 m));
+        let shelled = cuboid.shell(length!(0.5 m), &[]).unwrap();
+        assert!((shelled.volume() - 7.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn shell_two_open_faces() {
+        let cuboid = Cuboid::from_dim(length!(2 m), length!(2 m), length!(2 m));
+        let tube = cuboid
+            .shell(
+                length!(0.5 m),
+                &[FaceSelector::Parallel(Vec3::from((0., 0., 1.)))],
+            )
+            .unwrap();
+        assert!((tube.volume() - 6.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn shell_invalid_thickness() {
+        let cuboid = Cuboid::from_dim(length!(2 m), length!(2 m), length!(2 m));
+        assert_eq!(
+            cuboid.shell(Length::zero(), &[]),
+            Err(Error::Shell(Length::zero()))
+        );
+        assert_eq!(
+            Part::empty().shell(length!(1 mm), &[]),
+            Err(Error::EmptyPart)
+        );
+    }
+
+    #[test]
+    fn fillet_selected_matches_indices() {
+        let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+        let selector = EdgeSelector::Min(Vec3::from((1., 0., 0.)));
+        let indices: Vec<usize> = cuboid
+            .edges()
+            .iter()
+            .enumerate()
+            .filter(|(_, edge)| selector.select(&cuboid).contains(edge))
+            .map(|(index, _)| index)
+            .collect();
+        assert_eq!(indices.len(), 4);
+        assert_eq!(
+            cuboid.fillet_selected(length!(10 cm), &selector),
+            cuboid.fillet_edges(length!(10 cm), &indices)
+        );
+    }
+
+    #[test]
+    fn chamfer_selected_nothing() {
+        let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+        let selector = EdgeSelector::Type(CurveType::Circle);
+        assert_eq!(
+            cuboid.chamfer_selected(length!(10 cm), &selector),
+            Ok(cuboid)
+        );
+    }
+
+    #[test]
+    fn bounding_box_of_rotated_cuboid() {
+        let cuboid = Cuboid::from_dim(length!(2 m), length!(2 m), length!(2 m))
+            .rotate_around(Axis::z(), angle!(45 deg));
+        let (min, max) = cuboid.bounding_box().unwrap();
+        let half_diagonal = 2_f64.sqrt();
+        assert!((min.x.m() + half_diagonal).abs() < 1e-6);
+        assert!((max.y.m() - half_diagonal).abs() < 1e-6);
+        assert!((min.z.m() + 1.).abs() < 1e-6);
+        assert!((max.z.m() - 1.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn loose_bounding_box_contains_bounding_box() {
+        let sphere = Sphere::from_radius(length!(1 m)).move_to(Point3D::from_m(1., 2., 3.));
+        let (tight_min, tight_max) = sphere.bounding_box().unwrap();
+        let (loose_min, loose_max) = sphere.loose_bounding_box().unwrap();
+        assert!(loose_min.x <= tight_min.x && loose_max.x >= tight_max.x);
+        assert!(loose_min.y <= tight_min.y && loose_max.y >= tight_max.y);
+        assert!(loose_min.z <= tight_min.z && loose_max.z >= tight_max.z);
+        assert!((tight_min.z.m() - 2.).abs() < 1e-6);
+        assert!((tight_max.z.m() - 4.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn bounding_box_of_empty_part() {
+        assert_eq!(Part::empty().bounding_box(), Err(Error::EmptyPart));
+        assert_eq!(Part::empty().loose_bounding_box(), Err(Error::EmptyPart));
+    }
+
+    #[test]
+    fn bounding_box_of_disjoint_intersection() {
+        let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+        let empty = cuboid.intersect(&cuboid.move_to(Point3D::from_m(5., 0., 0.)));
+        assert!(empty.inner.is_some());
+        assert_eq!(empty.bounding_box(), Err(Error::EmptyPart));
+        assert_eq!(empty.loose_bounding_box(), Err(Error::EmptyPart));
+
+        let top = &cuboid.faces()[0];
+        let sketch = Rectangle::from_dim(length!(0.5 m), length!(0.5 m));
+        assert_eq!(
+            empty.cut_on_face(&sketch, top, CutDepth::ThroughAll),
+            Err(Error::EmptyPart)
+        );
+    }
+
+    #[test]
+    fn cut_on_face_through_all_of_rotated_part() {
+        let cylinder = Cylinder::from_radius(length!(2 m), length!(1 m))
+            .rotate_around(Axis::x(), angle!(90 deg));
+        let front = &FaceSelector::Min(Vec3::from((0., 1., 0.))).select(&cylinder)[0];
+        let hole = Circle::from_radius(length!(1 m));
+        let ring = cylinder
+            .cut_on_face(&hole, front, CutDepth::ThroughAll)
+            .unwrap();
+        assert!((ring.volume() - 3. * core::f64::consts::PI).abs() < 1e-6);
+    }
+
+    #[test]
+    fn on_face_of_empty_part() {
+        let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+        let top = &cuboid.faces()[0];
+        let sketch = Rectangle::from_dim(length!(1 m), length!(1 m));
+        assert_eq!(
+            Part::empty().cut_on_face(&sketch, top, CutDepth::ThroughAll),
+            Err(Error::EmptyPart)
+        );
+        assert_eq!(
+            Part::empty().extrude_on_face(&sketch, top, length!(1 m)),
+            Err(Error::EmptyPart)
+        );
+    }
+
+    #[test]
+    fn cut_on_face_with_non_positive_depth() {
+        let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+        let top = &cuboid.faces()[0];
+        let sketch = Rectangle::from_dim(length!(0.5 m), length!(0.5 m));
+        assert_eq!(
+            cuboid.cut_on_face(&sketch, top, CutDepth::Blind(length!(0))),
+            Err(Error::NonPositiveDepth(length!(0)))
+        );
+        assert_eq!(
+            cuboid.cut_on_face(&sketch, top, CutDepth::Blind(length!(-0.2 m))),
+            Err(Error::NonPositiveDepth(length!(-0.2 m)))
+        );
+    }
+
+    #[test]
+    fn extrude_on_face_with_non_positive_height() {
+        let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+        let top = &cuboid.faces()[0];
+        let sketch = Rectangle::from_dim(length!(0.5 m), length!(0.5 m));
+        assert_eq!(
+            cuboid.extrude_on_face(&sketch, top, length!(0)),
+            Err(Error::NonPositiveDepth(length!(0)))
+        );
+        assert_eq!(
+            cuboid.extrude_on_face(&sketch, top, length!(-0.2 m)),
+            Err(Error::NonPositiveDepth(length!(-0.2 m)))
+        );
+    }
+
+    #[test]
+    fn cut_on_curved_face() {
+        let sphere = Sphere::from_radius(length!(1 m));
+        let sketch = Circle::from_radius(length!(0.1 m));
+        assert_eq!(
+            sphere.cut_on_face(&sketch, &sphere.faces()[0], CutDepth::ThroughAll),
+            Err(Error::NonPlanarFace.context("cut_on_face", "face"))
+        );
+    }
+
+    #[test]
+    fn step_round_trip_cuboid() {
+        let dir = TempDir::new("anvil").unwrap();
+        let path = dir.path().join("cuboid.step");
+        let part = Cuboid::from_corners(Point3D::from_m(1, 2, 3), Point3D::from_m(2, 4, 6));
+
+        part.write_step(&path).unwrap();
+        assert_eq!(Part::read_step(&path), Ok(part))
+    }
+
+    #[test]
+    fn step_round_trip_rotated() {
+        let dir = TempDir::new("anvil").unwrap();
+        let path = dir.path().join("rotated.step");
+        let part = Cuboid::from_m(1, 2, 3)
+            .add(&Sphere::from_radius(length!(1 m)).move_to(Point3D::from_m(0, 0, 1.5)))
+            .rotate_around(Axis::x(), angle!(30 deg));
+
+        part.write_step(&path).unwrap();
+        assert_eq!(Part::read_step(&path), Ok(part))
+    }
+
+    #[test]
+    fn read_step_off_origin() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/tetrahedron_mm.step");
+        let part = Part::read_step(path).unwrap();
+
+        let (min, max) = part.bounding_box().unwrap();
+        assert!((min - Point3D::from_m(0.1, 0., 0.)).distance_to_origin() < length!(1e-9 m));
+        assert!((max - Point3D::from_m(0.11, 0.01, 0.01)).distance_to_origin() < length!(1e-9 m));
+        assert!((part.volume() - 1e-6 / 6.).abs() < 1e-15);
+    }
+
+    #[test]
+    fn read_step_invalid_file() {
+        let dir = TempDir::new("anvil").unwrap();
+        let path = dir.path().join("invalid.step");
+        fs::write(&path, "not a step file").unwrap();
+
+        assert_eq!(
+            Part::read_step(&path),
+            Err(Error::StepRead(path.clone()).context("read_step", path.display().to_string()))
+        )
+    }
+
+    /// The triangles of a tetrahedron with the corners at the origin and (1, 0, 0), (0, 1, 0), and
+    /// (0, 0, 1), each given by its normal and three vertices.
+    const TETRAHEDRON: [[[f32; 3]; 4]; 4] = [
+        [[0., 0., -1.], [0., 0., 0.], [0., 1., 0.], [1., 0., 0.]],
+        [[0., -1., 0.], [0., 0., 0.], [1., 0., 0.], [0., 0., 1.]],
+        [[-1., 0., 0.], [0., 0., 0.], [0., 0., 1.], [0., 1., 0.]],
+        [[1., 1., 1.], [1., 0., 0.], [0., 1., 0.], [0., 0., 1.]],
+    ];
+
+    /// The tetrahedron of `TETRAHEDRON` with all triangles wound inwards.
+    const INVERTED_TETRAHEDRON: [[[f32; 3]; 4]; 4] = [
+        [[0., 0., 1.], [0., 0., 0.], [1., 0., 0.], [0., 1., 0.]],
+        [[0., 1., 0.], [0., 0., 0.], [0., 0., 1.], [1., 0., 0.]],
+        [[1., 0., 0.], [0., 0., 0.], [0., 1., 0.], [0., 0., 1.]],
+        [[-1., -1., -1.], [1., 0., 0.], [0., 0., 1.], [0., 1., 0.]],
+    ];
+
+    fn ascii_stl(triangles: &[[[f32; 3]; 4]]) -> String {
+        let mut lines = vec!["solid test".to_string()];
+        for [normal, vertices @ ..] in triangles {
+            lines.push(format!(
+                "facet normal {} {} {}",
+                normal[0], normal[1], normal[2]
+            ));
+            lines.push("outer loop".into());
+            for vertex in vertices {
+                lines.push(format!("vertex {} {} {}", vertex[0], vertex[1], vertex[2]));
+            }
+            lines.push("endloop".into());
+            lines.push("endfacet".into());
+        }
+        lines.push("endsolid test".into());
+        lines.join("\n")
+    }
+
+    fn binary_stl(triangles: &[[[f32; 3]; 4]]) -> Vec<u8> {
+        let mut bytes = vec![0; 80];
+        bytes.extend((triangles.len() as u32).to_le_bytes());
+        for triangle in triangles {
+            for value in triangle.iter().flatten() {
+                bytes.extend(value.to_le_bytes());
+            }
+            bytes.extend(0_u16.to_le_bytes());
+        }
+        bytes
+    }
+
+    #[test]
+    fn read_stl_ascii() {
+        let dir = TempDir::new("anvil").unwrap();
+        let path = dir.path().join("ascii.stl");
+        fs::write(&path, ascii_stl(&TETRAHEDRON)).unwrap();
+
+        let part = Part::read_stl(&path).unwrap();
+        assert!((part.volume() - 1. / 6.).abs() < 1e-9)
+    }
+
+    #[test]
+    fn read_stl_binary() {
+        let dir = TempDir::new("anvil").unwrap();
+        let path = dir.path().join("binary.stl");
+        fs::write(&path, binary_stl(&TETRAHEDRON)).unwrap();
+
+        let part = Part::read_stl(&path).unwrap();
+        assert!((part.volume() - 1. / 6.).abs() < 1e-9)
+    }
+
+    #[test]
+    fn read_stl_inward_winding() {
+        let dir = TempDir::new("anvil").unwrap();
+        let path = dir.path().join("inverted.stl");
+        fs::write(&path, binary_stl(&INVERTED_TETRAHEDRON)).unwrap();
+
+        let part = Part::read_stl(&path).unwrap();
+        assert!((part.volume() - 1. / 6.).abs() < 1e-9);
+
+        let corner = Cuboid::from_corners(Point3D::origin(), Point3D::from_m(0.1, 0.1, 0.1));
+        assert!((part.subtract(&corner).volume() - (1. / 6. - 0.001)).abs() < 1e-9)
+    }
+
+    #[test]
+    fn read_stl_not_watertight() {
+        let dir = TempDir::new("anvil").unwrap();
+        let path = dir.path().join("open.stl");
+        fs::write(&path, ascii_stl(&TETRAHEDRON[..3])).unwrap();
+
+        assert_eq!(
+            Part::read_stl(&path),
+            Err(Error::NotWatertight(path.clone()).context("read_stl", path.display().to_string()))
+        )
+    }
+
+    #[test]
+    fn stl_round_trip_cuboid() {
+        let dir = TempDir::new("anvil").unwrap();
+        let path = dir.path().join("cuboid.stl");
+        let part = Cuboid::from_corners(Point3D::from_m(1, 2, 3), Point3D::from_m(2, 4, 6));
+
+        part.write_stl(&path).unwrap();
+        assert_eq!(Part::read_stl(&path), Ok(part))
+    }
+
+    #[test]
+    fn read_stl_can_be_subtracted_from() {
+        let dir = TempDir::new("anvil").unwrap();
+        let path = dir.path().join("cuboid.stl");
+        Cuboid::from_m(2, 2, 2).write_stl(&path).unwrap();
+
+        let part = Part::read_stl(&path).unwrap();
+        assert_eq!(
+            part.subtract(&Cuboid::from_corners(
+                Point3D::origin(),
+                Point3D::from_m(1, 1, 1)
+            )),
+            Cuboid::from_m(2, 2, 2).subtract(&Cuboid::from_corners(
+                Point3D::origin(),
+                Point3D::from_m(1, 1, 1)
+            ))
+        )
+    }
+
+    #[test]
+    fn write_stl_binary_size() {
+        let dir = TempDir::new("anvil").unwrap();
+        let path = dir.path().join("binary.stl");
+        let options = StlOptions {
+            binary: true,
+            ..Default::default()
+        };
+        Cuboid::from_m(1, 1, 1)
+            .write_stl_with_options(&path, &options)
+            .unwrap();
+
+        let n_triangles = 12;
+        assert_eq!(fs::read(&path).unwrap().len(), 84 + 50 * n_triangles)
+    }
+
+    #[test]
+    fn write_stl_in_millimeters() {
+        let dir = TempDir::new("anvil").unwrap();
+        let path = dir.path().join("millimeters.stl");
+        let options = StlOptions {
+            unit: length!(1 mm),
+            ..Default::default()
+        };
+        let cuboid = Cuboid::from_m(1, 2, 3);
+        cuboid.write_stl_with_options(&path, &options).unwrap();
+
+        assert_eq!(Part::read_stl(&path), Ok(cuboid.scale(1000.)))
+    }
+
+    #[test]
+    fn write_stl_zero_unit() {
+        let options = StlOptions {
+            unit: Length::zero(),
+            ..Default::default()
+        };
+        assert_eq!(
+            Cuboid::from_m(1, 1, 1).write_stl_with_options("out.stl", &options),
+            Err(Error::StlWrite("out.stl".into()))
+        )
+    }
+
+    #[test]
+    fn stl_with_options_ignores_binary() {
+        let options = StlOptions {
+            binary: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            Cuboid::from_m(1, 1, 1).stl_with_options(&options),
+            Cuboid::from_m(1, 1, 1).stl()
+        )
+    }
+
     #[test]
     fn part_moved_twice() {
         let part = Cuboid::from_m(1., 1., 1.);
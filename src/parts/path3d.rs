@@ -0,0 +1,522 @@
+use core::f64;
+
+use cxx::UniquePtr;
+use opencascade_sys::ffi;
+
+use crate::{Axis, Error, Length, Point3D, Vec3};
+
+/// A continuous series of edges (i.e. lines, arcs, helices, ...) in 3D space.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Path3D {
+    cursor: Point3D,
+    segments: Vec<Segment>,
+}
+impl Path3D {
+    /// Construct an empty `Path3D` at a given starting point.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Path3D, Point3D};
+    ///
+    /// let path = Path3D::at(Point3D::from_m(1., 2., 3.));
+    /// assert_eq!(path.start(), Point3D::from_m(1., 2., 3.))
+    /// ```
+    pub fn at(start: Point3D) -> Self {
+        Self {
+            cursor: start,
+            segments: vec![],
+        }
+    }
+
+    /// Add a line to the end of this `Path3D` that ends at a specified point.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Path3D, Point3D};
+    ///
+    /// let path = Path3D::at(Point3D::origin()).line_to(Point3D::from_m(1., 2., 3.));
+    /// assert_eq!(path.end(), Point3D::from_m(1., 2., 3.))
+    /// ```
+    pub fn line_to(&self, point: Point3D) -> Self {
+        self.add_segment(Segment::Line(self.cursor, point))
+    }
+
+    /// Add a circular arc to the end of this `Path3D` that passes through a point and ends at
+    /// another.
+    ///
+    /// If the three points of the arc are collinear or coincide, an `Err(Error::Arc3D)` is
+    /// returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Path3D, Point3D};
+    ///
+    /// let path = Path3D::at(Point3D::from_m(1., 0., 0.))
+    ///     .arc_through(Point3D::from_m(0., 1., 0.), Point3D::from_m(-1., 0., 0.))
+    ///     .unwrap();
+    /// assert_eq!(path.end(), Point3D::from_m(-1., 0., 0.))
+    /// ```
+    pub fn arc_through(&self, mid: Point3D, end: Point3D) -> Result<Self, Error> {
+        if is_collinear(self.cursor, mid, end) {
+            return Err(Error::Arc3D(end));
+        }
+        Ok(self.add_segment(Segment::Arc(self.cursor, mid, end)))
+    }
+
+    /// Add a helix to the end of this `Path3D` that winds around an `Axis`.
+    ///
+    /// The helix starts at the current end of the `Path3D` and its radius is the distance of that
+    /// point to the axis. For positive pitches, the helix turns according to the right-hand-rule
+    /// around the axis direction. The height is measured along the axis direction.
+    ///
+    /// If the pitch or the height is zero, or if the current end of the `Path3D` lies on the axis,
+    /// an `Err(Error::Helix)` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Axis, length, Path3D, Point3D};
+    ///
+    /// let path = Path3D::at(Point3D::from_m(1., 0., 0.))
+    ///     .helix_around(Axis::z(), length!(1 m), length!(3 m))
+    ///     .unwrap();
+    /// let end = path.end();
+    /// assert!((end.x.m() - 1.).abs() < 1e-9);
+    /// assert!(end.y.m().abs() < 1e-9);
+    /// assert!((end.z.m() - 3.).abs() < 1e-9);
+    /// ```
+    pub fn helix_around(&self, axis: Axis, pitch: Length, height: Length) -> Result<Self, Error> {
+        if pitch == Length::zero()
+            || height == Length::zero()
+            || HelixFrame::new(self.cursor, &axis).is_none()
+        {
+            return Err(Error::Helix(self.cursor));
+        }
+        Ok(self.add_segment(Segment::Helix {
+            start: self.cursor,
+            axis,
+            pitch,
+            height,
+        }))
+    }
+
+    /// Return the starting point of the `Path3D`.
+    ///
+    /// If the path does not have any edges, the cursor is returned.
+    pub fn start(&self) -> Point3D {
+        match self.segments.first() {
+            Some(segment) => segment.start(),
+            None => self.cursor,
+        }
+    }
+
+    /// Return the ending point of the `Path3D`.
+    ///
+    /// If the path does not have any edges, the cursor is returned.
+    pub fn end(&self) -> Point3D {
+        match self.segments.last() {
+            Some(segment) => segment.end(),
+            None => self.cursor,
+        }
+    }
+
+    /// Return a number of points that are spaced evenly along this `Path3D`.
+    ///
+    /// The first point is the start of the path. On an open path, the last point is its end; on a
+    /// closed path, the points are spread around the whole loop without repeating the start.
+    pub(crate) fn points_along(&self, count: usize) -> Result<Vec<Point3D>, Error> {
+        Ok(points_along_edges(&self.occt_edges()?, count))
+    }
+
+    pub(crate) fn to_occt(&self) -> Result<UniquePtr<ffi::TopoDS_Wire>, Error> {
+        let mut make_wire = ffi::BRepBuilderAPI_MakeWire_ctor();
+        for edge in self.occt_edges()? {
+            make_wire.pin_mut().add_edge(&edge)
+        }
+        if !make_wire.IsDone() {
+            return Err(Error::Wire);
+        }
+        let wire = ffi::TopoDS_Wire_to_owned(make_wire.pin_mut().Wire());
+        ffi::BRepLib_BuildCurves3d(&wire);
+        Ok(wire)
+    }
+
+    /// Return the OpenCascade edges of all segments of this `Path3D`.
+    ///
+    /// If the path is empty, an `Err(Error::EmptyPath)` is returned. If one of the segments can
+    /// not be converted, its error is returned instead of leaving a gap in the path.
+    fn occt_edges(&self) -> Result<Vec<UniquePtr<ffi::TopoDS_Edge>>, Error> {
+        if self.segments.is_empty() {
+            return Err(Error::EmptyPath);
+        }
+        self.segments.iter().map(Segment::to_occt).collect()
+    }
+
+    fn add_segment(&self, segment: Segment) -> Self {
+        let new_cursor = segment.end();
+        let mut new_segments = self.segments.clone();
+        new_segments.push(segment);
+
+        Self {
+            cursor: new_cursor,
+            segments: new_segments,
+        }
+    }
+}
+
+/// Number of samples per edge that are used to approximate the arc length along the edges.
+const ARC_LENGTH_SAMPLES: usize = 64;
+
+/// Return a number of points that are spaced evenly by arc length along consecutive edges.
+///
+/// The arc length is approximated by sampling each edge, and the points are then evaluated on the
+/// edges themselves so that they lie exactly on the curves.
+pub(crate) fn points_along_edges(
+    edges: &[UniquePtr<ffi::TopoDS_Edge>],
+    count: usize,
+) -> Vec<Point3D> {
+    let curves: Vec<UniquePtr<ffi::BRepAdaptor_Curve>> = edges
+        .iter()
+        .map(|edge| ffi::BRepAdaptor_Curve_ctor(edge))
+        .collect();
+
+    // (index of the curve, parameter on the curve, arc length from the start of the edges)
+    let mut samples: Vec<(usize, f64, f64)> = vec![];
+    let mut total_length = 0.;
+    for (index, curve) in curves.iter().enumerate() {
+        let first = curve.FirstParameter();
+        let last = curve.LastParameter();
+        let mut previous = point_at(curve, first);
+        samples.push((index, first, total_length));
+        for step in 1..=ARC_LENGTH_SAMPLES {
+            let parameter = first + (last - first) * step as f64 / ARC_LENGTH_SAMPLES as f64;
+            let point = point_at(curve, parameter);
+            total_length += (point - previous).distance_to_origin().m();
+            samples.push((index, parameter, total_length));
+            previous = point;
+        }
+    }
+
+    let (first_index, first_parameter, _) = samples[0];
+    let (last_index, last_parameter, _) = samples[samples.len() - 1];
+    let start = point_at(&curves[first_index], first_parameter);
+    let end = point_at(&curves[last_index], last_parameter);
+    let is_closed = (end - start).distance_to_origin().m() < 1e-9;
+
+    let spacing = match (is_closed, count) {
+        (_, 0) => return vec![],
+        (false, 1) => 0.,
+        (false, _) => total_length / (count - 1) as f64,
+        (true, _) => total_length / count as f64,
+    };
+
+    (0..count)
+        .map(|instance| {
+            let target = spacing * instance as f64;
+            let (index, parameter) = samples
+                .windows(2)
+                .filter(|pair| pair[0].0 == pair[1].0)
+                .find(|pair| pair[1].2 >= target)
+                .map(|pair| {
+                    let (index, start_parameter, start_length) = pair[0];
+                    let (_, end_parameter, end_length) = pair[1];
+                    let section = end_length - start_length;
+                    let fraction = if section > 0. {
+                        (target - start_length) / section
+                    } else {
+                        0.
+                    };
+                    (
+                        index,
+                        start_parameter + (end_parameter - start_parameter) * fraction,
+                    )
+                })
+                .unwrap_or((last_index, last_parameter));
+            point_at(&curves[index], parameter)
+        })
+        .collect()
+}
+
+/// Return true if three points lie on a single line, which includes coinciding points.
+fn is_collinear(start: Point3D, mid: Point3D, end: Point3D) -> bool {
+    let to_mid = (mid - start) / Length::from_m(1.);
+    let to_end = (end - start) / Length::from_m(1.);
+    to_mid.cross(to_end).magnitude() < 1e-12
+}
+
+/// Return the point at a parameter of a curve.
+fn point_at(curve: &ffi::BRepAdaptor_Curve, parameter: f64) -> Point3D {
+    let point = ffi::BRepAdaptor_Curve_value(curve, parameter);
+    Point3D::from_m(point.X(), point.Y(), point.Z())
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum Segment {
+    Arc(Point3D, Point3D, Point3D),
+    Helix {
+        start: Point3D,
+        axis: Axis,
+        pitch: Length,
+        height: Length,
+    },
+    Line(Point3D, Point3D),
+}
+impl Segment {
+    fn start(&self) -> Point3D {
+        match self {
+            Segment::Arc(start, _, _) => *start,
+            Segment::Helix { start, .. } => *start,
+            Segment::Line(start, _) => *start,
+        }
+    }
+
+    fn end(&self) -> Point3D {
+        match self {
+            Segment::Arc(_, _, end) => *end,
+            Segment::Helix {
+                start,
+                axis,
+                pitch,
+                height,
+            } => match HelixFrame::new(*start, axis) {
+                Some(frame) => frame.point_at(*height / *pitch * f64::consts::TAU, *height),
+                None => *start,
+            },
+            Segment::Line(_, end) => *end,
+        }
+    }
+
+    fn to_occt(&self) -> Result<UniquePtr<ffi::TopoDS_Edge>, Error> {
+        match self {
+            Segment::Arc(start, mid, end) => {
+                if is_collinear(*start, *mid, *end) {
+                    return Err(Error::Arc3D(*end));
+                }
+                let make_arc = ffi::GC_MakeArcOfCircle_point_point_point(
+                    &start.to_occt_point(),
+                    &mid.to_occt_point(),
+                    &end.to_occt_point(),
+                );
+                let curve = ffi::new_HandleGeomCurve_from_HandleGeom_TrimmedCurve(
+                    &ffi::GC_MakeArcOfCircle_Value(&make_arc),
+                );
+                let mut constructor = ffi::BRepBuilderAPI_MakeEdge_HandleGeomCurve(&curve);
+                Ok(ffi::TopoDS_Edge_to_owned(constructor.pin_mut().Edge()))
+            }
+            Segment::Helix {
+                start,
+                axis,
+                pitch,
+                height,
+            } => {
+                let frame = match HelixFrame::new(*start, axis) {
+                    Some(frame) if *pitch != Length::zero() && *height != Length::zero() => frame,
+                    _ => return Err(Error::Helix(*start)),
+                };
+
+                let cylinder = ffi::Geom_CylindricalSurface_ctor(
+                    &ffi::gp_Ax3_from_gp_Ax2(&axis.to_occt_ax2()),
+                    frame.radius.m(),
+                );
+                let surface = ffi::cylinder_to_surface(&cylinder);
+
+                let projection =
+                    ffi::GeomAPI_ProjectPointOnSurf_ctor(&start.to_occt_point(), &surface);
+                let mut u = 0.;
+                let mut v = 0.;
+                projection.LowerDistanceParameters(&mut u, &mut v);
+
+                let segment = ffi::GCE2d_MakeSegment_point_point(
+                    &ffi::new_point_2d(u, v),
+                    &ffi::new_point_2d(u + *height / *pitch * f64::consts::TAU, v + height.m()),
+                );
+                let mut constructor = ffi::BRepBuilderAPI_MakeEdge_CurveSurface2d(
+                    &ffi::HandleGeom2d_TrimmedCurve_to_curve(&segment),
+                    &surface,
+                );
+                Ok(ffi::TopoDS_Edge_to_owned(constructor.pin_mut().Edge()))
+            }
+            Segment::Line(start, end) => {
+                if start == end {
+                    return Err(Error::Line3D(*end));
+                }
+                let mut constructor = ffi::BRepBuilderAPI_MakeEdge_gp_Pnt_gp_Pnt(
+                    &start.to_occt_point(),
+                    &end.to_occt_point(),
+                );
+                Ok(ffi::TopoDS_Edge_to_owned(constructor.pin_mut().Edge()))
+            }
+        }
+    }
+}
+
+/// The cylindrical coordinate system in which a helix is described.
+struct HelixFrame {
+    foot: Point3D,
+    direction: Vec3,
+    radial: Vec3,
+    radius: Length,
+}
+impl HelixFrame {
+    /// Construct the frame of a helix starting at a point and winding around an axis.
+    ///
+    /// Returns `None` if the axis has no direction or the point lies on the axis.
+    fn new(start: Point3D, axis: &Axis) -> Option<Self> {
+        let direction = axis.direction.normalize().ok()?;
+        let relative = (start - axis.origin) / Length::from_m(1.);
+        let along = relative.dot(direction);
+        let radial = relative - direction * along;
+        let radius = Length::from_m(radial.magnitude());
+        if radius == Length::zero() {
+            return None;
+        }
+
+        Some(Self {
+            foot: axis.origin + direction * Length::from_m(along),
+            direction,
+            radial: radial.normalize().ok()?,
+            radius,
+        })
+    }
+
+    /// Return the point of the helix after it turned by an angle in radians and rose by a height.
+    fn point_at(&self, angle: f64, height: Length) -> Point3D {
+        let tangential = self.direction.cross(self.radial);
+        let radial = self.radial * angle.cos() + tangential * angle.sin();
+        self.foot + radial * self.radius + self.direction * height
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::length;
+
+    fn assert_close(left: Point3D, right: Point3D) {
+        assert!((left - right).distance_to_origin().m() < 1e-9)
+    }
+
+    #[test]
+    fn arc_through_collinear() {
+        assert_eq!(
+            Path3D::at(Point3D::origin())
+                .arc_through(Point3D::from_m(1, 1, 1), Point3D::from_m(2, 2, 2)),
+            Err(Error::Arc3D(Point3D::from_m(2, 2, 2)))
+        )
+    }
+
+    #[test]
+    fn arc_through_coincident() {
+        assert_eq!(
+            Path3D::at(Point3D::from_m(1, 0, 0))
+                .arc_through(Point3D::from_m(0, 1, 0), Point3D::from_m(1, 0, 0)),
+            Err(Error::Arc3D(Point3D::from_m(1, 0, 0)))
+        )
+    }
+
+    #[test]
+    fn helix_quarter_turn() {
+        let path = Path3D::at(Point3D::from_m(1, 0, 0))
+            .helix_around(Axis::z(), length!(1 m), length!(0.25 m))
+            .unwrap();
+        assert_close(path.end(), Point3D::from_m(0., 1., 0.25))
+    }
+
+    #[test]
+    fn helix_left_handed() {
+        let path = Path3D::at(Point3D::from_m(1, 0, 0))
+            .helix_around(Axis::z(), length!(-1 m), length!(0.25 m))
+            .unwrap();
+        assert_close(path.end(), Point3D::from_m(0., -1., 0.25))
+    }
+
+    #[test]
+    fn helix_around_offset_axis() {
+        let axis = Axis::new(Point3D::from_m(1, 1, 0), (0., 0., 2.));
+        let path = Path3D::at(Point3D::from_m(2, 1, 5))
+            .helix_around(axis, length!(2 m), length!(1 m))
+            .unwrap();
+        assert_close(path.end(), Point3D::from_m(0., 1., 6.))
+    }
+
+    #[test]
+    fn helix_start_on_axis() {
+        assert_eq!(
+            Path3D::at(Point3D::origin()).helix_around(Axis::z(), length!(1 m), length!(1 m)),
+            Err(Error::Helix(Point3D::origin()))
+        )
+    }
+
+    #[test]
+    fn helix_zero_pitch_or_height() {
+        let path = Path3D::at(Point3D::from_m(1, 0, 0));
+        assert_eq!(
+            path.helix_around(Axis::z(), length!(0), length!(1 m)),
+            Err(Error::Helix(Point3D::from_m(1, 0, 0)))
+        );
+        assert_eq!(
+            path.helix_around(Axis::z(), length!(1 m), length!(0)),
+            Err(Error::Helix(Point3D::from_m(1, 0, 0)))
+        );
+    }
+
+    #[test]
+    fn to_occt_zero_length_line() {
+        let path = Path3D::at(Point3D::origin())
+            .line_to(Point3D::from_m(1, 0, 0))
+            .line_to(Point3D::from_m(1, 0, 0));
+        assert!(matches!(path.to_occt(), Err(Error::Line3D(_))));
+        assert_eq!(
+            path.points_along(2),
+            Err(Error::Line3D(Point3D::from_m(1, 0, 0)))
+        );
+    }
+
+    #[test]
+    fn points_along_line() {
+        let path = Path3D::at(Point3D::origin()).line_to(Point3D::from_m(0, 0, 3));
+        let points = path.points_along(4).unwrap();
+        assert_eq!(points.len(), 4);
+        for (point, z) in points.into_iter().zip([0., 1., 2., 3.]) {
+            assert_close(point, Point3D::from_m(0., 0., z))
+        }
+    }
+
+    #[test]
+    fn points_along_multiple_segments() {
+        let path = Path3D::at(Point3D::origin())
+            .line_to(Point3D::from_m(1, 0, 0))
+            .line_to(Point3D::from_m(1, 3, 0));
+        let points = path.points_along(3).unwrap();
+        assert_close(points[0], Point3D::origin());
+        assert_close(points[1], Point3D::from_m(1., 1., 0.));
+        assert_close(points[2], Point3D::from_m(1., 3., 0.));
+    }
+
+    #[test]
+    fn points_along_closed_path() {
+        let path = Path3D::at(Point3D::from_m(1, 0, 0))
+            .arc_through(Point3D::from_m(0, 1, 0), Point3D::from_m(-1, 0, 0))
+            .unwrap()
+            .arc_through(Point3D::from_m(0, -1, 0), Point3D::from_m(1, 0, 0))
+            .unwrap();
+        let points = path.points_along(4).unwrap();
+        assert_eq!(points.len(), 4);
+        assert_close(points[0], Point3D::from_m(1., 0., 0.));
+        assert_close(points[1], Point3D::from_m(0., 1., 0.));
+        assert_close(points[2], Point3D::from_m(-1., 0., 0.));
+        assert_close(points[3], Point3D::from_m(0., -1., 0.));
+    }
+
+    #[test]
+    fn points_along_empty() {
+        assert_eq!(
+            Path3D::at(Point3D::origin()).points_along(3),
+            Err(Error::EmptyPath)
+        )
+    }
+
+    #[test]
+    fn to_occt_empty() {
+        assert!(Path3D::at(Point3D::origin()).to_occt().is_err())
+    }
+}
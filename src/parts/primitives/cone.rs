@@ -0,0 +1,103 @@
+use crate::{Angle, Length, Part, quantities::is_zero};
+use opencascade_sys::ffi;
+
+/// Builder for a conical `Part`.
+///
+/// While the `Cone` struct itself is not used, its constructor methods like `Cone::from_radii()`
+/// can be used to build this primitive `Part`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Cone;
+impl Cone {
+    /// Construct a conical `Part` or a frustum from the radii of its bottom and top faces and its
+    /// height.
+    ///
+    /// The axis of the cone lies on the z-axis and its bounding box is centered around the origin.
+    /// If both radii are zero or if a radius or the height is negative, an empty `Part` is
+    /// returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use core::f64;
+    /// use anvil::{Cone, length, Point3D};
+    ///
+    /// let part = Cone::from_radii(length!(1 m), length!(0), length!(4 m));
+    /// assert_eq!(part.center(), Ok(Point3D::from_m(0., 0., -1.)));
+    /// assert!((part.volume() - 4. / 3. * f64::consts::PI).abs() < 1e-5);
+    /// ```
+    pub fn from_radii(bottom_radius: Length, top_radius: Length, height: Length) -> Part {
+        if is_zero(&[height]) || is_zero(&[bottom_radius + top_radius]) {
+            return Part::empty();
+        }
+        if bottom_radius < Length::zero() || top_radius < Length::zero() || height < Length::zero()
+        {
+            return Part::empty();
+        }
+        let axis = ffi::gp_Ax2_ctor(
+            &ffi::new_point(0., 0., -height.m() / 2.),
+            &ffi::gp_Dir_ctor(0., 0., 1.),
+        );
+        let mut make =
+            ffi::BRepPrimAPI_MakeCone_ctor(&axis, bottom_radius.m(), top_radius.m(), height.m());
+        Part::from_occt(make.pin_mut().Shape())
+    }
+
+    /// Construct a conical `Part` from the radius of its base and the angle at its tip.
+    ///
+    /// The base of the cone lies at the bottom and its tip at the top. This shape is commonly used
+    /// for countersinks. If the angle is not between 0 and 180 degrees, an empty `Part` is
+    /// returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{angle, Cone, length};
+    ///
+    /// let part = Cone::from_angle(length!(1 m), angle!(90 deg));
+    /// assert_eq!(part, Cone::from_radii(length!(1 m), length!(0), length!(1 m)));
+    /// ```
+    pub fn from_angle(radius: Length, angle: Angle) -> Part {
+        if angle <= Angle::zero() || angle >= Angle::from_deg(180) {
+            return Part::empty();
+        }
+        let height = radius / (angle.rad() / 2.).tan();
+        Self::from_radii(radius, Length::zero(), height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Point3D, angle, length};
+
+    #[test]
+    fn from_radii_frustum() {
+        let part = Cone::from_radii(length!(2 m), length!(1 m), length!(28 m));
+        let expected_volume = core::f64::consts::PI * 28. / 3. * (4. + 2. + 1.);
+        assert!((part.volume() - expected_volume).abs() < 1e-5);
+
+        let center_above_bottom = 28. * (4. + 2. * 2. + 3.) / (4. * (4. + 2. + 1.));
+        assert_eq!(
+            part.center(),
+            Ok(Point3D::from_m(0., 0., center_above_bottom - 14.))
+        );
+    }
+
+    #[test]
+    fn from_radii_empty() {
+        assert!(Cone::from_radii(length!(0), length!(0), length!(1 m)) == Part::empty());
+        assert!(Cone::from_radii(length!(1 m), length!(1 m), length!(0)) == Part::empty());
+    }
+
+    #[test]
+    fn from_radii_negative() {
+        assert!(Cone::from_radii(length!(-1 m), length!(0), length!(1 m)) == Part::empty());
+        assert!(Cone::from_radii(length!(2 m), length!(-1 m), length!(1 m)) == Part::empty());
+        assert!(Cone::from_radii(length!(1 m), length!(0), length!(-1 m)) == Part::empty());
+    }
+
+    #[test]
+    fn from_angle_empty() {
+        assert!(Cone::from_angle(length!(1 m), angle!(0 deg)) == Part::empty());
+        assert!(Cone::from_angle(length!(1 m), angle!(180 deg)) == Part::empty());
+        assert!(Cone::from_angle(length!(0), angle!(90 deg)) == Part::empty());
+    }
+}
@@ -1,7 +1,15 @@
+mod cone;
 mod cuboid;
 mod cylinder;
+mod prism;
 mod sphere;
+mod torus;
+mod wedge;
 
+pub use cone::Cone;
 pub use cuboid::Cuboid;
 pub use cylinder::Cylinder;
+pub use prism::Prism;
 pub use sphere::Sphere;
+pub use torus::Torus;
+pub use wedge::Wedge;
@@ -0,0 +1,84 @@
+use crate::{Length, Part, Plane, Point3D, Polygon, Sketch, quantities::is_zero};
+
+/// Builder for a `Part` in the shape of a regular prism.
+///
+/// While the `Prism` struct itself is not used, its constructor methods like
+/// `Prism::from_circumradius()` can be used to build this primitive `Part`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Prism;
+impl Prism {
+    /// Construct a centered regular prism `Part` along the z-axis from the radius of the circle
+    /// through the corners of its base, the number of sides and the height.
+    ///
+    /// The first corner lies on the positive x-axis. If less than three sides are given, an empty
+    /// `Part` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{length, Point3D, Prism};
+    ///
+    /// let part = Prism::from_circumradius(length!(1 m), 4, length!(2 m));
+    /// assert_eq!(part.center(), Ok(Point3D::origin()));
+    /// assert!((part.volume() - 4.).abs() < 1e-5);
+    /// ```
+    pub fn from_circumradius(radius: Length, sides: usize, height: Length) -> Part {
+        Self::extrude_base(&Polygon::from_circumradius(radius, sides), height)
+    }
+
+    /// Construct a centered regular prism `Part` along the z-axis from the radius of the circle
+    /// that touches the sides of its base, the number of sides and the height.
+    ///
+    /// If less than three sides are given, an empty `Part` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{length, Point3D, Prism};
+    ///
+    /// let part = Prism::from_inradius(length!(1 m), 4, length!(2 m));
+    /// assert_eq!(part.center(), Ok(Point3D::origin()));
+    /// assert!((part.volume() - 8.).abs() < 1e-5);
+    /// ```
+    pub fn from_inradius(radius: Length, sides: usize, height: Length) -> Part {
+        Self::extrude_base(&Polygon::from_inradius(radius, sides), height)
+    }
+
+    fn extrude_base(base: &Sketch, height: Length) -> Part {
+        if is_zero(&[height]) {
+            return Part::empty();
+        }
+        let plane = Plane::new(
+            Point3D::new(Length::zero(), Length::zero(), height * -0.5),
+            (1., 0., 0.),
+            (0., 1., 0.),
+        );
+        match plane.and_then(|plane| base.extrude(&plane, height)) {
+            Ok(part) => part,
+            Err(_) => Part::empty(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::length;
+
+    #[test]
+    fn from_circumradius_hexagon() {
+        let part = Prism::from_circumradius(length!(1 m), 6, length!(1 m));
+        assert!((part.volume() - 3. * f64::sqrt(3.) / 2.).abs() < 1e-5);
+    }
+
+    #[test]
+    fn from_circumradius_empty() {
+        assert!(Prism::from_circumradius(length!(0), 6, length!(1 m)) == Part::empty());
+        assert!(Prism::from_circumradius(length!(1 m), 2, length!(1 m)) == Part::empty());
+        assert!(Prism::from_circumradius(length!(1 m), 6, length!(0)) == Part::empty());
+    }
+
+    #[test]
+    fn from_inradius_empty() {
+        assert!(Prism::from_inradius(length!(0), 6, length!(1 m)) == Part::empty());
+        assert!(Prism::from_inradius(length!(1 m), 6, length!(0)) == Part::empty());
+    }
+}
@@ -0,0 +1,64 @@
+use crate::{Length, Part, quantities::is_zero};
+use opencascade_sys::ffi;
+
+/// Builder for a toroidal `Part`.
+///
+/// While the `Torus` struct itself is not used, its constructor methods like `Torus::from_radii()`
+/// can be used to build this primitive `Part`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Torus;
+impl Torus {
+    /// Construct a centered toroidal `Part` around the z-axis.
+    ///
+    /// The major radius is the distance from the center of the torus to the center of its tube,
+    /// the minor radius is the radius of the tube. If a radius is not positive or if the minor
+    /// radius is not smaller than the major radius, an empty `Part` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use core::f64;
+    /// use anvil::{length, Point3D, Torus};
+    ///
+    /// let part = Torus::from_radii(length!(2 m), length!(1 m));
+    /// assert_eq!(part.center(), Ok(Point3D::origin()));
+    /// assert!((part.volume() - 4. * f64::consts::PI.powi(2)).abs() < 1e-5);
+    /// ```
+    pub fn from_radii(major_radius: Length, minor_radius: Length) -> Part {
+        if is_zero(&[major_radius, minor_radius]) {
+            return Part::empty();
+        }
+        if minor_radius < Length::zero() || minor_radius >= major_radius {
+            return Part::empty();
+        }
+        let axis = ffi::gp_Ax2_ctor(&ffi::new_point(0., 0., 0.), &ffi::gp_Dir_ctor(0., 0., 1.));
+        let mut make = ffi::BRepPrimAPI_MakeTorus_ctor(&axis, major_radius.m(), minor_radius.m());
+        Part::from_occt(make.pin_mut().Shape())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::length;
+
+    #[test]
+    fn from_radii_volume() {
+        let part = Torus::from_radii(length!(3 m), length!(0.5 m));
+        let expected_volume = 2. * core::f64::consts::PI.powi(2) * 3. * 0.5_f64.powi(2);
+        assert!((part.volume() - expected_volume).abs() < 1e-5);
+    }
+
+    #[test]
+    fn from_radii_empty() {
+        assert!(Torus::from_radii(length!(0), length!(1 m)) == Part::empty());
+        assert!(Torus::from_radii(length!(1 m), length!(0)) == Part::empty());
+    }
+
+    #[test]
+    fn from_radii_invalid() {
+        assert!(Torus::from_radii(length!(-2 m), length!(1 m)) == Part::empty());
+        assert!(Torus::from_radii(length!(2 m), length!(-1 m)) == Part::empty());
+        assert!(Torus::from_radii(length!(1 m), length!(1 m)) == Part::empty());
+        assert!(Torus::from_radii(length!(1 m), length!(2 m)) == Part::empty());
+    }
+}
@@ -0,0 +1,57 @@
+use crate::{Length, Part, Plane, Point2D, Point3D, Polygon, quantities::is_zero};
+
+/// Builder for a wedge-shaped `Part`.
+///
+/// While the `Wedge` struct itself is not used, its constructor methods like `Wedge::from_dim()`
+/// can be used to build this primitive `Part`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Wedge;
+impl Wedge {
+    /// Construct a wedge-shaped `Part` from the x, y, and z dimensions of its bounding box.
+    ///
+    /// The cross section of the wedge in the xz-plane is a right triangle with the right angle at
+    /// the bottom in negative x direction. The bounding box is centered around the origin.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{length, Point3D, Wedge};
+    ///
+    /// let part = Wedge::from_dim(length!(6 m), length!(2 m), length!(6 m));
+    /// assert_eq!(part.center(), Ok(Point3D::from_m(-1., 0., -1.)));
+    /// assert!((part.volume() - 36.).abs() < 1e-5);
+    /// ```
+    pub fn from_dim(x: Length, y: Length, z: Length) -> Part {
+        if is_zero(&[x, y, z]) {
+            return Part::empty();
+        }
+        let profile = Polygon::from_points(&[
+            Point2D::new(x * -0.5, z * -0.5),
+            Point2D::new(x * 0.5, z * -0.5),
+            Point2D::new(x * -0.5, z * 0.5),
+        ]);
+
+        // the normal of this plane points in negative y direction
+        let plane = Plane::new(
+            Point3D::new(Length::zero(), y * 0.5, Length::zero()),
+            (1., 0., 0.),
+            (0., 0., 1.),
+        );
+        match plane.and_then(|plane| profile.extrude(&plane, y)) {
+            Ok(part) => part,
+            Err(_) => Part::empty(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::length;
+
+    #[test]
+    fn from_dim_empty() {
+        assert!(Wedge::from_dim(length!(0), length!(1 m), length!(1 m)) == Part::empty());
+        assert!(Wedge::from_dim(length!(1 m), length!(0), length!(1 m)) == Part::empty());
+        assert!(Wedge::from_dim(length!(1 m), length!(1 m), length!(0)) == Part::empty());
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{Angle, Length};
+
+/// Options that define how a `Part` is converted into an STL mesh.
+///
+/// # Example
+/// ```rust
+/// use anvil::{length, StlOptions};
+///
+/// let options = StlOptions {
+///     linear_deflection: length!(0.01 mm),
+///     binary: true,
+///     ..Default::default()
+/// };
+/// assert_eq!(options.unit, length!(1 m));
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct StlOptions {
+    /// The maximum distance between a triangle of the mesh and the true surface of the `Part`.
+    pub linear_deflection: Length,
+
+    /// The maximum angle between the normals of two adjacent triangles on a curved surface.
+    pub angular_deflection: Angle,
+
+    /// If true, the binary STL format is written, otherwise the ASCII format.
+    pub binary: bool,
+
+    /// The length that corresponds to a value of 1 in the file.
+    pub unit: Length,
+}
+impl Default for StlOptions {
+    fn default() -> Self {
+        Self {
+            linear_deflection: Length::from_mm(0.1),
+            angular_deflection: Angle::from_rad(0.5),
+            binary: false,
+            unit: Length::from_m(1.),
+        }
+    }
+}
@@ -0,0 +1,9 @@
+/// The orientation of a profile while it is swept along a path.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SweepOrientation {
+    /// Keep the orientation of the profile constant along the whole path.
+    Fixed,
+
+    /// Rotate the profile with the Frenet frame (tangent, normal, and binormal) of the path.
+    Frenet,
+}
@@ -0,0 +1,173 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use super::into_f64::IntoF64;
+
+/// A physical area (i.e. the size of a surface).
+///
+/// Area exists to remove ambiguity about area units, which scale with the square of the length
+/// unit they are derived from.
+///
+/// ```rust
+/// use anvil::Area;
+///
+/// // You can construct an area using the Area::from_[unit] methods like
+/// let square_meters_area = Area::from_m2(1.5);
+/// let square_millimeters_area = Area::from_mm2(400.);
+///
+/// // To get back an area value in a specific unit, call the Area.[unit] method
+/// assert_eq!(square_meters_area.cm2(), 15000.);
+/// assert!((square_millimeters_area.cm2() - 4.).abs() < 1e-9);
+/// ```
+#[derive(Debug, PartialEq, Copy, Clone, PartialOrd)]
+pub struct Area {
+    square_meters: f64,
+}
+impl Area {
+    /// Construct an `Area` with a value of zero.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::Area;
+    ///
+    /// let area = Area::zero();
+    /// assert_eq!(area.m2(), 0.);
+    /// ```
+    pub fn zero() -> Self {
+        Self { square_meters: 0. }
+    }
+    /// Construct an `Area` from a value of unit square meters.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::Area;
+    ///
+    /// let area = Area::from_m2(2.);
+    /// assert_eq!(area.cm2(), 20000.);
+    /// ```
+    pub fn from_m2<T: IntoF64>(value: T) -> Self {
+        Self {
+            square_meters: value.into_f64(),
+        }
+    }
+    /// Return the value of this area in square meters.
+    pub fn m2(&self) -> f64 {
+        self.square_meters
+    }
+    /// Construct an `Area` from a value of unit square centimeters.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::Area;
+    ///
+    /// let area = Area::from_cm2(5.);
+    /// assert_eq!(area.m2(), 0.0005);
+    /// ```
+    pub fn from_cm2<T: IntoF64>(value: T) -> Self {
+        Self::from_m2(value.into_f64() / 10_000.)
+    }
+    /// Return the value of this area in square centimeters.
+    pub fn cm2(&self) -> f64 {
+        self.m2() * 10_000.
+    }
+    /// Construct an `Area` from a value of unit square millimeters.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::Area;
+    ///
+    /// let area = Area::from_mm2(3.);
+    /// assert_eq!(area.m2(), 0.000003);
+    /// ```
+    pub fn from_mm2<T: IntoF64>(value: T) -> Self {
+        Self::from_m2(value.into_f64() / 1_000_000.)
+    }
+    /// Return the value of this area in square millimeters.
+    pub fn mm2(&self) -> f64 {
+        self.m2() * 1_000_000.
+    }
+    /// Construct an `Area` from a value of unit square inches.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::Area;
+    ///
+    /// let area = Area::from_in2(1.);
+    /// assert_eq!(area.m2(), 0.00064516);
+    /// ```
+    pub fn from_in2<T: IntoF64>(value: T) -> Self {
+        Self::from_m2(value.into_f64() * 0.00064516)
+    }
+    /// Return the value of this area in square inches.
+    pub fn in2(&self) -> f64 {
+        self.m2() / 0.00064516
+    }
+}
+
+impl Add<Area> for Area {
+    type Output = Area;
+    fn add(self, other: Area) -> Area {
+        Area::from_m2(self.m2() + other.m2())
+    }
+}
+
+impl Sub<Area> for Area {
+    type Output = Area;
+    fn sub(self, other: Area) -> Area {
+        Area::from_m2(self.m2() - other.m2())
+    }
+}
+
+impl Mul<f64> for Area {
+    type Output = Area;
+    fn mul(self, other: f64) -> Area {
+        Area::from_m2(self.m2() * other)
+    }
+}
+
+impl Mul<Area> for f64 {
+    type Output = Area;
+    fn mul(self, other: Area) -> Area {
+        other * self
+    }
+}
+
+impl Div<f64> for Area {
+    type Output = Area;
+    fn div(self, other: f64) -> Area {
+        Area::from_m2(self.m2() / other)
+    }
+}
+
+impl Div<Area> for Area {
+    type Output = f64;
+    /// Divide an `Area` by another `Area`.
+    /// ```rust
+    /// use anvil::Area;
+    ///
+    /// assert_eq!(Area::from_m2(6.) / Area::from_m2(2.), 3.)
+    /// ```
+    fn div(self, other: Area) -> f64 {
+        self.square_meters / other.square_meters
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add() {
+        assert_eq!(Area::from_m2(2.) + Area::from_m2(3.), Area::from_m2(5.));
+    }
+
+    #[test]
+    fn subtract() {
+        assert_eq!(Area::from_m2(3.) - Area::from_m2(2.), Area::from_m2(1.));
+    }
+
+    #[test]
+    fn multiply_with_f64() {
+        assert_eq!(Area::from_m2(5.) * 4., Area::from_m2(20.));
+        assert_eq!(4. * Area::from_m2(5.), Area::from_m2(20.));
+    }
+}
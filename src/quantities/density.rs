@@ -0,0 +1,154 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use super::{Mass, Volume, into_f64::IntoF64};
+
+/// A physical density (i.e. the mass of a material per volume).
+///
+/// Density exists to remove ambiguity about density units, which combine a mass unit with a volume
+/// unit.
+///
+/// ```rust
+/// use anvil::Density;
+///
+/// // You can construct a density using the Density::from_[unit] methods like
+/// let steel = Density::from_kg_m3(7850.);
+/// let aluminium = Density::from_g_cm3(2.7);
+///
+/// // To get back a density value in a specific unit, call the Density.[unit] method
+/// assert_eq!(steel.g_cm3(), 7.85);
+/// assert_eq!(aluminium.kg_m3(), 2700.);
+/// ```
+#[derive(Debug, PartialEq, Copy, Clone, PartialOrd)]
+pub struct Density {
+    kilograms_per_cubic_meter: f64,
+}
+impl Density {
+    /// Construct a `Density` with a value of zero.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::Density;
+    ///
+    /// let density = Density::zero();
+    /// assert_eq!(density.kg_m3(), 0.);
+    /// ```
+    pub fn zero() -> Self {
+        Self {
+            kilograms_per_cubic_meter: 0.,
+        }
+    }
+    /// Construct a `Density` from a value of unit kilograms per cubic meter.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::Density;
+    ///
+    /// let density = Density::from_kg_m3(1000.);
+    /// assert_eq!(density.g_cm3(), 1.);
+    /// ```
+    pub fn from_kg_m3<T: IntoF64>(value: T) -> Self {
+        Self {
+            kilograms_per_cubic_meter: value.into_f64(),
+        }
+    }
+    /// Return the value of this density in kilograms per cubic meter.
+    pub fn kg_m3(&self) -> f64 {
+        self.kilograms_per_cubic_meter
+    }
+    /// Construct a `Density` from a value of unit grams per cubic centimeter.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::Density;
+    ///
+    /// let density = Density::from_g_cm3(1.2);
+    /// assert_eq!(density.kg_m3(), 1200.);
+    /// ```
+    pub fn from_g_cm3<T: IntoF64>(value: T) -> Self {
+        Self::from_kg_m3(value.into_f64() * 1000.)
+    }
+    /// Return the value of this density in grams per cubic centimeter.
+    pub fn g_cm3(&self) -> f64 {
+        self.kg_m3() / 1000.
+    }
+}
+
+impl Add<Density> for Density {
+    type Output = Density;
+    fn add(self, other: Density) -> Density {
+        Density::from_kg_m3(self.kg_m3() + other.kg_m3())
+    }
+}
+
+impl Sub<Density> for Density {
+    type Output = Density;
+    fn sub(self, other: Density) -> Density {
+        Density::from_kg_m3(self.kg_m3() - other.kg_m3())
+    }
+}
+
+impl Mul<f64> for Density {
+    type Output = Density;
+    fn mul(self, other: f64) -> Density {
+        Density::from_kg_m3(self.kg_m3() * other)
+    }
+}
+
+impl Mul<Density> for f64 {
+    type Output = Density;
+    fn mul(self, other: Density) -> Density {
+        other * self
+    }
+}
+
+impl Mul<Volume> for Density {
+    type Output = Mass;
+    /// Multiply a `Density` with a `Volume` to get the `Mass` of the material in it.
+    /// ```rust
+    /// use anvil::{Density, Mass, Volume};
+    ///
+    /// assert_eq!(Density::from_kg_m3(1000.) * Volume::from_m3(2.), Mass::from_kg(2000.))
+    /// ```
+    fn mul(self, other: Volume) -> Mass {
+        other * self
+    }
+}
+
+impl Div<f64> for Density {
+    type Output = Density;
+    fn div(self, other: f64) -> Density {
+        Density::from_kg_m3(self.kg_m3() / other)
+    }
+}
+
+impl Div<Density> for Density {
+    type Output = f64;
+    /// Divide a `Density` by another `Density`.
+    /// ```rust
+    /// use anvil::Density;
+    ///
+    /// assert_eq!(Density::from_kg_m3(6.) / Density::from_kg_m3(2.), 3.)
+    /// ```
+    fn div(self, other: Density) -> f64 {
+        self.kilograms_per_cubic_meter / other.kilograms_per_cubic_meter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add() {
+        assert_eq!(
+            Density::from_kg_m3(2.) + Density::from_kg_m3(3.),
+            Density::from_kg_m3(5.)
+        );
+    }
+
+    #[test]
+    fn multiply_with_f64() {
+        assert_eq!(Density::from_kg_m3(5.) * 4., Density::from_kg_m3(20.));
+        assert_eq!(4. * Density::from_kg_m3(5.), Density::from_kg_m3(20.));
+    }
+}
@@ -0,0 +1,169 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use super::{Density, Volume, into_f64::IntoF64};
+
+/// A physical mass.
+///
+/// Mass exists to remove ambiguity about mass units, which are not supported by default by major
+/// CAD kernels.
+///
+/// ```rust
+/// use anvil::Mass;
+///
+/// // You can construct a mass using the Mass::from_[unit] methods like
+/// let kilograms_mass = Mass::from_kg(1.5);
+/// let pounds_mass = Mass::from_lb(2.);
+///
+/// // To get back a mass value in a specific unit, call the Mass.[unit] method
+/// assert_eq!(kilograms_mass.g(), 1500.);
+/// assert_eq!(pounds_mass.kg(), 0.90718474);
+/// ```
+#[derive(Debug, PartialEq, Copy, Clone, PartialOrd)]
+pub struct Mass {
+    kilograms: f64,
+}
+impl Mass {
+    /// Construct a `Mass` with a value of zero.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::Mass;
+    ///
+    /// let mass = Mass::zero();
+    /// assert_eq!(mass.kg(), 0.);
+    /// ```
+    pub fn zero() -> Self {
+        Self { kilograms: 0. }
+    }
+    /// Construct a `Mass` from a value of unit kilograms.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::Mass;
+    ///
+    /// let mass = Mass::from_kg(3.2);
+    /// assert_eq!(mass.g(), 3200.);
+    /// ```
+    pub fn from_kg<T: IntoF64>(value: T) -> Self {
+        Self {
+            kilograms: value.into_f64(),
+        }
+    }
+    /// Return the value of this mass in kilograms.
+    pub fn kg(&self) -> f64 {
+        self.kilograms
+    }
+    /// Construct a `Mass` from a value of unit grams.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::Mass;
+    ///
+    /// let mass = Mass::from_g(5.);
+    /// assert_eq!(mass.kg(), 0.005);
+    /// ```
+    pub fn from_g<T: IntoF64>(value: T) -> Self {
+        Self::from_kg(value.into_f64() / 1000.)
+    }
+    /// Return the value of this mass in grams.
+    pub fn g(&self) -> f64 {
+        self.kg() * 1000.
+    }
+    /// Construct a `Mass` from a value of unit pounds.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::Mass;
+    ///
+    /// let mass = Mass::from_lb(1.);
+    /// assert_eq!(mass.kg(), 0.45359237);
+    /// ```
+    pub fn from_lb<T: IntoF64>(value: T) -> Self {
+        Self::from_kg(value.into_f64() * 0.45359237)
+    }
+    /// Return the value of this mass in pounds.
+    pub fn lb(&self) -> f64 {
+        self.kg() / 0.45359237
+    }
+}
+
+impl Add<Mass> for Mass {
+    type Output = Mass;
+    fn add(self, other: Mass) -> Mass {
+        Mass::from_kg(self.kg() + other.kg())
+    }
+}
+
+impl Sub<Mass> for Mass {
+    type Output = Mass;
+    fn sub(self, other: Mass) -> Mass {
+        Mass::from_kg(self.kg() - other.kg())
+    }
+}
+
+impl Mul<f64> for Mass {
+    type Output = Mass;
+    fn mul(self, other: f64) -> Mass {
+        Mass::from_kg(self.kg() * other)
+    }
+}
+
+impl Mul<Mass> for f64 {
+    type Output = Mass;
+    fn mul(self, other: Mass) -> Mass {
+        other * self
+    }
+}
+
+impl Div<f64> for Mass {
+    type Output = Mass;
+    fn div(self, other: f64) -> Mass {
+        Mass::from_kg(self.kg() / other)
+    }
+}
+
+impl Div<Mass> for Mass {
+    type Output = f64;
+    /// Divide a `Mass` by another `Mass`.
+    /// ```rust
+    /// use anvil::Mass;
+    ///
+    /// assert_eq!(Mass::from_kg(6.) / Mass::from_kg(2.), 3.)
+    /// ```
+    fn div(self, other: Mass) -> f64 {
+        self.kilograms / other.kilograms
+    }
+}
+
+impl Div<Volume> for Mass {
+    type Output = Density;
+    /// Divide a `Mass` by the `Volume` it occupies to get its `Density`.
+    /// ```rust
+    /// use anvil::{Density, Mass, Volume};
+    ///
+    /// assert_eq!(Mass::from_kg(6.) / Volume::from_m3(2.), Density::from_kg_m3(3.))
+    /// ```
+    fn div(self, other: Volume) -> Density {
+        Density::from_kg_m3(self.kg() / other.m3())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add() {
+        assert_eq!(Mass::from_kg(2.) + Mass::from_kg(3.), Mass::from_kg(5.));
+    }
+
+    #[test]
+    fn subtract() {
+        assert_eq!(Mass::from_kg(3.) - Mass::from_kg(2.), Mass::from_kg(1.));
+    }
+
+    #[test]
+    fn divide_with_f64() {
+        assert_eq!(Mass::from_kg(6.) / 2., Mass::from_kg(3.));
+    }
+}
@@ -1,17 +1,29 @@
 mod angle;
+mod area;
 mod axis;
+mod density;
 mod into_f64;
 mod length;
+mod mass;
+mod moment_of_inertia;
 mod plane;
 mod point2d;
 mod point3d;
+mod vec2;
 mod vec3;
+mod volume;
 
 pub use angle::Angle;
+pub use area::Area;
 pub use axis::Axis;
+pub use density::Density;
 pub use into_f64::IntoF64;
 pub use length::{Length, is_zero};
+pub use mass::Mass;
+pub use moment_of_inertia::MomentOfInertia;
 pub use plane::Plane;
 pub use point2d::Point2D;
 pub use point3d::Point3D;
+pub use vec2::Vec2;
 pub use vec3::Vec3;
+pub use volume::Volume;
@@ -0,0 +1,184 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use super::{Area, Mass, into_f64::IntoF64};
+
+/// A physical moment of inertia (i.e. the resistance of a body against rotation around an axis).
+///
+/// MomentOfInertia exists to remove ambiguity about the units of rotational mass, which combine a
+/// mass unit with the square of a length unit. Products of inertia share the same unit.
+///
+/// ```rust
+/// use anvil::MomentOfInertia;
+///
+/// // You can construct a moment of inertia using the MomentOfInertia::from_[unit] methods like
+/// let si_moment = MomentOfInertia::from_kg_m2(0.5);
+/// let small_moment = MomentOfInertia::from_kg_mm2(3.);
+///
+/// // To get back a value in a specific unit, call the MomentOfInertia.[unit] method
+/// assert_eq!(si_moment.kg_cm2(), 5000.);
+/// assert_eq!(small_moment.kg_m2(), 0.000003);
+/// ```
+#[derive(Debug, PartialEq, Copy, Clone, PartialOrd)]
+pub struct MomentOfInertia {
+    kilogram_square_meters: f64,
+}
+impl MomentOfInertia {
+    /// Construct a `MomentOfInertia` with a value of zero.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::MomentOfInertia;
+    ///
+    /// let moment = MomentOfInertia::zero();
+    /// assert_eq!(moment.kg_m2(), 0.);
+    /// ```
+    pub fn zero() -> Self {
+        Self {
+            kilogram_square_meters: 0.,
+        }
+    }
+    /// Construct a `MomentOfInertia` from a value of unit kilogram square meters.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::MomentOfInertia;
+    ///
+    /// let moment = MomentOfInertia::from_kg_m2(2.);
+    /// assert_eq!(moment.kg_cm2(), 20000.);
+    /// ```
+    pub fn from_kg_m2<T: IntoF64>(value: T) -> Self {
+        Self {
+            kilogram_square_meters: value.into_f64(),
+        }
+    }
+    /// Return the value of this moment of inertia in kilogram square meters.
+    pub fn kg_m2(&self) -> f64 {
+        self.kilogram_square_meters
+    }
+    /// Construct a `MomentOfInertia` from a value of unit kilogram square centimeters.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::MomentOfInertia;
+    ///
+    /// let moment = MomentOfInertia::from_kg_cm2(5.);
+    /// assert_eq!(moment.kg_m2(), 0.0005);
+    /// ```
+    pub fn from_kg_cm2<T: IntoF64>(value: T) -> Self {
+        Self::from_kg_m2(value.into_f64() / 10_000.)
+    }
+    /// Return the value of this moment of inertia in kilogram square centimeters.
+    pub fn kg_cm2(&self) -> f64 {
+        self.kg_m2() * 10_000.
+    }
+    /// Construct a `MomentOfInertia` from a value of unit kilogram square millimeters.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::MomentOfInertia;
+    ///
+    /// let moment = MomentOfInertia::from_kg_mm2(7.);
+    /// assert_eq!(moment.kg_m2(), 0.000007);
+    /// ```
+    pub fn from_kg_mm2<T: IntoF64>(value: T) -> Self {
+        Self::from_kg_m2(value.into_f64() / 1_000_000.)
+    }
+    /// Return the value of this moment of inertia in kilogram square millimeters.
+    pub fn kg_mm2(&self) -> f64 {
+        self.kg_m2() * 1_000_000.
+    }
+}
+
+impl Add<MomentOfInertia> for MomentOfInertia {
+    type Output = MomentOfInertia;
+    fn add(self, other: MomentOfInertia) -> MomentOfInertia {
+        MomentOfInertia::from_kg_m2(self.kg_m2() + other.kg_m2())
+    }
+}
+
+impl Sub<MomentOfInertia> for MomentOfInertia {
+    type Output = MomentOfInertia;
+    fn sub(self, other: MomentOfInertia) -> MomentOfInertia {
+        MomentOfInertia::from_kg_m2(self.kg_m2() - other.kg_m2())
+    }
+}
+
+impl Mul<f64> for MomentOfInertia {
+    type Output = MomentOfInertia;
+    fn mul(self, other: f64) -> MomentOfInertia {
+        MomentOfInertia::from_kg_m2(self.kg_m2() * other)
+    }
+}
+
+impl Mul<MomentOfInertia> for f64 {
+    type Output = MomentOfInertia;
+    fn mul(self, other: MomentOfInertia) -> MomentOfInertia {
+        other * self
+    }
+}
+
+impl Div<f64> for MomentOfInertia {
+    type Output = MomentOfInertia;
+    fn div(self, other: f64) -> MomentOfInertia {
+        MomentOfInertia::from_kg_m2(self.kg_m2() / other)
+    }
+}
+
+impl Div<MomentOfInertia> for MomentOfInertia {
+    type Output = f64;
+    /// Divide a `MomentOfInertia` by another `MomentOfInertia`.
+    /// ```rust
+    /// use anvil::MomentOfInertia;
+    ///
+    /// assert_eq!(MomentOfInertia::from_kg_m2(6.) / MomentOfInertia::from_kg_m2(2.), 3.)
+    /// ```
+    fn div(self, other: MomentOfInertia) -> f64 {
+        self.kilogram_square_meters / other.kilogram_square_meters
+    }
+}
+
+impl Div<Mass> for MomentOfInertia {
+    type Output = Area;
+    /// Divide a `MomentOfInertia` by a `Mass` to get the square of the radius of gyration.
+    /// ```rust
+    /// use anvil::{Area, Mass, MomentOfInertia};
+    ///
+    /// assert_eq!(MomentOfInertia::from_kg_m2(6.) / Mass::from_kg(2.), Area::from_m2(3.))
+    /// ```
+    fn div(self, other: Mass) -> Area {
+        Area::from_m2(self.kg_m2() / other.kg())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add() {
+        assert_eq!(
+            MomentOfInertia::from_kg_m2(2.) + MomentOfInertia::from_kg_m2(3.),
+            MomentOfInertia::from_kg_m2(5.)
+        );
+    }
+
+    #[test]
+    fn subtract() {
+        assert_eq!(
+            MomentOfInertia::from_kg_m2(3.) - MomentOfInertia::from_kg_m2(2.),
+            MomentOfInertia::from_kg_m2(1.)
+        );
+    }
+
+    #[test]
+    fn multiply_with_f64() {
+        assert_eq!(
+            MomentOfInertia::from_kg_m2(5.) * 4.,
+            MomentOfInertia::from_kg_m2(20.)
+        );
+        assert_eq!(
+            4. * MomentOfInertia::from_kg_m2(5.),
+            MomentOfInertia::from_kg_m2(20.)
+        );
+    }
+}
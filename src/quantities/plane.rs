@@ -1,6 +1,6 @@
 use crate::Error;
 
-use super::{Axis, Point3D, vec3::Vec3};
+use super::{Axis, Length, Point3D, vec3::Vec3};
 
 /// A 2D plane in 3D space.
 #[derive(Debug, PartialEq, Clone)]
@@ -63,6 +63,13 @@ impl Plane {
             direction: self.normal(),
         }
     }
+
+    /// Return true if this `Plane` and another span the same infinite surface.
+    pub(crate) fn is_coplanar_with(&self, other: &Plane) -> bool {
+        let normals_are_parallel = self.normal().cross(other.normal()).magnitude() < 1e-9;
+        let offset = (other.origin() - self.origin()) / Length::from_m(1.);
+        normals_are_parallel && offset.dot(self.normal()).abs() < 1e-9
+    }
 }
 
 #[cfg(test)]
@@ -82,6 +89,18 @@ mod tests {
         )
     }
 
+    #[test]
+    fn is_coplanar_with_shifted_origin() {
+        let plane = Plane::new(Point3D::from_m(1., 2., 0.), (1., 0., 0.), (0., 1., 0.)).unwrap();
+        assert!(plane.is_coplanar_with(&Plane::xy()))
+    }
+
+    #[test]
+    fn is_not_coplanar_with_parallel_plane() {
+        let plane = Plane::new(Point3D::from_m(0., 0., 1.), (1., 0., 0.), (0., 1., 0.)).unwrap();
+        assert!(!plane.is_coplanar_with(&Plane::xy()))
+    }
+
     #[test]
     fn new_non_orthogonal_vector() {
         let plane = Plane::new(Point3D::origin(), (2., 0., 0.), (0., 2., 0.));
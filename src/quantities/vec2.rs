@@ -0,0 +1,86 @@
+use std::ops::{Add, Mul, Sub};
+
+use crate::Error;
+
+use super::{Length, Point2D};
+
+/// A unitless vector in 2D space.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Vec2 {
+    pub x: f64,
+    pub y: f64,
+}
+impl Vec2 {
+    /// Construct a `Vec2` from the directional components.
+    pub fn from(vec: (f64, f64)) -> Self {
+        Vec2 { x: vec.0, y: vec.1 }
+    }
+
+    /// Return the absolute length of this `Vec2`.
+    pub fn magnitude(&self) -> f64 {
+        (self.x.powi(2) + self.y.powi(2)).sqrt()
+    }
+
+    /// Return a `Vec2` that has the same direction as this one but a magnitude of 1.
+    pub fn normalize(&self) -> Result<Self, Error> {
+        let mag = self.magnitude();
+        if mag == 0. {
+            Err(Error::ZeroVector2D(*self))
+        } else {
+            Ok(Self {
+                x: self.x / mag,
+                y: self.y / mag,
+            })
+        }
+    }
+
+    /// Return the dot-product of this `Vec2` with another.
+    pub fn dot(&self, other: Vec2) -> f64 {
+        self.x * other.x + self.y * other.y
+    }
+}
+
+impl Add<Vec2> for Vec2 {
+    type Output = Vec2;
+    fn add(self, other: Vec2) -> Vec2 {
+        Vec2::from((self.x + other.x, self.y + other.y))
+    }
+}
+
+impl Sub<Vec2> for Vec2 {
+    type Output = Vec2;
+    fn sub(self, other: Vec2) -> Vec2 {
+        Vec2::from((self.x - other.x, self.y - other.y))
+    }
+}
+
+impl Mul<Length> for Vec2 {
+    type Output = Point2D;
+    fn mul(self, other: Length) -> Point2D {
+        Point2D {
+            x: self.x * other,
+            y: self.y * other,
+        }
+    }
+}
+
+impl Mul<Vec2> for Length {
+    type Output = Point2D;
+    fn mul(self, other: Vec2) -> Point2D {
+        other * self
+    }
+}
+
+impl Mul<f64> for Vec2 {
+    type Output = Vec2;
+    fn mul(self, other: f64) -> Vec2 {
+        Vec2::from((self.x * other, self.y * other))
+    }
+}
+
+impl Mul<Vec2> for f64 {
+    type Output = Vec2;
+    fn mul(self, other: Vec2) -> Vec2 {
+        other * self
+    }
+}
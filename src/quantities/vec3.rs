@@ -1,4 +1,4 @@
-use std::ops::Mul;
+use std::ops::{Add, Mul, Sub};
 
 use cxx::UniquePtr;
 use opencascade_sys::ffi;
@@ -65,6 +65,20 @@ impl Vec3 {
     }
 }
 
+impl Add<Vec3> for Vec3 {
+    type Output = Vec3;
+    fn add(self, other: Vec3) -> Vec3 {
+        Vec3::from((self.x + other.x, self.y + other.y, self.z + other.z))
+    }
+}
+
+impl Sub<Vec3> for Vec3 {
+    type Output = Vec3;
+    fn sub(self, other: Vec3) -> Vec3 {
+        Vec3::from((self.x - other.x, self.y - other.y, self.z - other.z))
+    }
+}
+
 impl Mul<Length> for Vec3 {
     type Output = Point3D;
     fn mul(self, other: Length) -> Point3D {
@@ -0,0 +1,210 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use super::{Density, Mass, into_f64::IntoF64};
+
+/// A physical volume (i.e. the space enclosed by a body).
+///
+/// Volume exists to remove ambiguity about volume units, which scale with the cube of the length
+/// unit they are derived from.
+///
+/// ```rust
+/// use anvil::Volume;
+///
+/// // You can construct a volume using the Volume::from_[unit] methods like
+/// let cubic_meters_volume = Volume::from_m3(0.5);
+/// let liters_volume = Volume::from_l(2.);
+///
+/// // To get back a volume value in a specific unit, call the Volume.[unit] method
+/// assert_eq!(cubic_meters_volume.l(), 500.);
+/// assert_eq!(liters_volume.m3(), 0.002);
+/// ```
+#[derive(Debug, PartialEq, Copy, Clone, PartialOrd)]
+pub struct Volume {
+    cubic_meters: f64,
+}
+impl Volume {
+    /// Construct a `Volume` with a value of zero.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::Volume;
+    ///
+    /// let volume = Volume::zero();
+    /// assert_eq!(volume.m3(), 0.);
+    /// ```
+    pub fn zero() -> Self {
+        Self { cubic_meters: 0. }
+    }
+    /// Construct a `Volume` from a value of unit cubic meters.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::Volume;
+    ///
+    /// let volume = Volume::from_m3(2.);
+    /// assert_eq!(volume.l(), 2000.);
+    /// ```
+    pub fn from_m3<T: IntoF64>(value: T) -> Self {
+        Self {
+            cubic_meters: value.into_f64(),
+        }
+    }
+    /// Return the value of this volume in cubic meters.
+    pub fn m3(&self) -> f64 {
+        self.cubic_meters
+    }
+    /// Construct a `Volume` from a value of unit liters.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::Volume;
+    ///
+    /// let volume = Volume::from_l(5.);
+    /// assert_eq!(volume.m3(), 0.005);
+    /// ```
+    pub fn from_l<T: IntoF64>(value: T) -> Self {
+        Self::from_m3(value.into_f64() / 1000.)
+    }
+    /// Return the value of this volume in liters.
+    pub fn l(&self) -> f64 {
+        self.m3() * 1000.
+    }
+    /// Construct a `Volume` from a value of unit cubic centimeters.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::Volume;
+    ///
+    /// let volume = Volume::from_cm3(3.);
+    /// assert_eq!(volume.m3(), 0.000003);
+    /// ```
+    pub fn from_cm3<T: IntoF64>(value: T) -> Self {
+        Self::from_m3(value.into_f64() / 1_000_000.)
+    }
+    /// Return the value of this volume in cubic centimeters.
+    pub fn cm3(&self) -> f64 {
+        self.m3() * 1_000_000.
+    }
+    /// Construct a `Volume` from a value of unit cubic millimeters.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::Volume;
+    ///
+    /// let volume = Volume::from_mm3(7.);
+    /// assert_eq!(volume.m3(), 0.000000007);
+    /// ```
+    pub fn from_mm3<T: IntoF64>(value: T) -> Self {
+        Self::from_m3(value.into_f64() / 1_000_000_000.)
+    }
+    /// Return the value of this volume in cubic millimeters.
+    pub fn mm3(&self) -> f64 {
+        self.m3() * 1_000_000_000.
+    }
+    /// Construct a `Volume` from a value of unit cubic inches.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::Volume;
+    ///
+    /// let volume = Volume::from_in3(1.);
+    /// assert_eq!(volume.m3(), 0.000016387064);
+    /// ```
+    pub fn from_in3<T: IntoF64>(value: T) -> Self {
+        Self::from_m3(value.into_f64() * 0.000016387064)
+    }
+    /// Return the value of this volume in cubic inches.
+    pub fn in3(&self) -> f64 {
+        self.m3() / 0.000016387064
+    }
+}
+
+impl Add<Volume> for Volume {
+    type Output = Volume;
+    fn add(self, other: Volume) -> Volume {
+        Volume::from_m3(self.m3() + other.m3())
+    }
+}
+
+impl Sub<Volume> for Volume {
+    type Output = Volume;
+    fn sub(self, other: Volume) -> Volume {
+        Volume::from_m3(self.m3() - other.m3())
+    }
+}
+
+impl Mul<f64> for Volume {
+    type Output = Volume;
+    fn mul(self, other: f64) -> Volume {
+        Volume::from_m3(self.m3() * other)
+    }
+}
+
+impl Mul<Volume> for f64 {
+    type Output = Volume;
+    fn mul(self, other: Volume) -> Volume {
+        other * self
+    }
+}
+
+impl Mul<Density> for Volume {
+    type Output = Mass;
+    /// Multiply a `Volume` with a `Density` to get the `Mass` of the material in it.
+    /// ```rust
+    /// use anvil::{Density, Mass, Volume};
+    ///
+    /// assert_eq!(Volume::from_m3(2.) * Density::from_kg_m3(1000.), Mass::from_kg(2000.))
+    /// ```
+    fn mul(self, other: Density) -> Mass {
+        Mass::from_kg(self.m3() * other.kg_m3())
+    }
+}
+
+impl Div<f64> for Volume {
+    type Output = Volume;
+    fn div(self, other: f64) -> Volume {
+        Volume::from_m3(self.m3() / other)
+    }
+}
+
+impl Div<Volume> for Volume {
+    type Output = f64;
+    /// Divide a `Volume` by another `Volume`.
+    /// ```rust
+    /// use anvil::Volume;
+    ///
+    /// assert_eq!(Volume::from_m3(6.) / Volume::from_m3(2.), 3.)
+    /// ```
+    fn div(self, other: Volume) -> f64 {
+        self.cubic_meters / other.cubic_meters
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add() {
+        assert_eq!(
+            Volume::from_m3(2.) + Volume::from_m3(3.),
+            Volume::from_m3(5.)
+        );
+    }
+
+    #[test]
+    fn subtract() {
+        assert_eq!(
+            Volume::from_m3(3.) - Volume::from_m3(2.),
+            Volume::from_m3(1.)
+        );
+    }
+
+    #[test]
+    fn multiply_with_density() {
+        assert_eq!(
+            Volume::from_l(1.) * Density::from_g_cm3(1.),
+            Mass::from_kg(1.)
+        );
+    }
+}
@@ -3,16 +3,35 @@ use core::f64;
 use cxx::UniquePtr;
 use opencascade_sys::ffi;
 
-use crate::{Length, Plane, Point2D, quantities::Axis};
+use crate::{Error, Length, Plane, Point2D, quantities::Axis};
+
+/// The maximum number of control points of an `Edge::Bezier` that OpenCascade can handle.
+const MAX_BEZIER_POINTS: usize = 25;
 
 /// A one-dimensional object in two-dimensional space.
 #[derive(Debug, PartialEq, Clone)]
 pub enum Edge {
+    /// A circular arc from a start point through a mid point to an end point.
+    Arc(Point2D, Point2D, Point2D),
+
+    /// A Bezier curve defined by its control points.
+    ///
+    /// The curve starts at the first and ends at the last control point. Three control points
+    /// describe a quadratic and four control points a cubic Bezier curve. Between 2 and 25
+    /// control points are supported.
+    Bezier(Vec<Point2D>),
+
     /// A circle at a center with a radius.
     Circle(Point2D, Length),
 
+    /// An ellipse at a center with its semi-axes in x and y direction.
+    Ellipse(Point2D, Length, Length),
+
     /// A line between two points.
     Line(Point2D, Point2D),
+
+    /// A smooth B-spline curve that is interpolated through a series of at least two points.
+    Spline(Vec<Point2D>),
 }
 impl Edge {
     /// Return the starting point of the edge.
@@ -26,8 +45,12 @@ impl Edge {
     /// ```
     pub fn start(&self) -> Point2D {
         match self {
+            Edge::Arc(start, _, _) => *start,
+            Edge::Bezier(points) => points.first().copied().unwrap_or_default(),
             Edge::Circle(center, _) => *center,
+            Edge::Ellipse(center, _, _) => *center,
             Edge::Line(start, _) => *start,
+            Edge::Spline(points) => points.first().copied().unwrap_or_default(),
         }
     }
     /// Return the ending point of the edge.
@@ -41,14 +64,59 @@ impl Edge {
     /// ```
     pub fn end(&self) -> Point2D {
         match self {
+            Edge::Arc(_, _, end) => *end,
+            Edge::Bezier(points) => points.last().copied().unwrap_or_default(),
             Edge::Circle(center, _) => *center,
+            Edge::Ellipse(center, _, _) => *center,
             Edge::Line(_, end) => *end,
+            Edge::Spline(points) => points.last().copied().unwrap_or_default(),
+        }
+    }
+
+    /// Return an `Err` if the points of a `Bezier` or `Spline` can not describe a curve.
+    pub(crate) fn validate(&self) -> Result<(), Error> {
+        match self {
+            Edge::Bezier(points) if !(2..=MAX_BEZIER_POINTS).contains(&points.len()) => {
+                Err(Error::Bezier(points.len()))
+            }
+            Edge::Spline(points) if points.len() < 2 => Err(Error::Spline(points.len())),
+            _ => Ok(()),
         }
     }
 
     /// Return the distance spanned by the `Edge`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use core::f64;
+    /// use anvil::{Edge, Point2D};
+    ///
+    /// let edge = Edge::Arc(
+    ///     Point2D::from_m(1., 0.),
+    ///     Point2D::from_m(0., 1.),
+    ///     Point2D::from_m(-1., 0.),
+    /// );
+    /// assert!((edge.len().m() - f64::consts::PI).abs() < 1e-9)
+    /// ```
     pub fn len(&self) -> Length {
         match self {
+            Edge::Arc(start, mid, end) => match arc_geometry(*start, *mid, *end) {
+                Some((_, radius, sweep)) => radius * sweep.abs(),
+                None => Length::zero(),
+            },
+            Edge::Bezier(_) | Edge::Ellipse(..) | Edge::Spline(_) => {
+                match self.curve_to_occt(&Plane::xy()) {
+                    Some(edge) => {
+                        let mut gprops = ffi::GProp_GProps_ctor();
+                        ffi::BRepGProp_LinearProperties(
+                            &ffi::cast_edge_to_shape(&edge),
+                            gprops.pin_mut(),
+                        );
+                        Length::from_m(gprops.Mass())
+                    }
+                    None => Length::zero(),
+                }
+            }
             Edge::Circle(_, radius) => *radius * f64::consts::TAU,
             Edge::Line(start, end) => {
                 let diff = *start - *end;
@@ -57,11 +125,86 @@ impl Edge {
         }
     }
 
+    /// Return the direction in which the `Edge` is heading at its end as a unit vector in meters.
+    ///
+    /// Returns `None` for closed edges and edges without length.
+    pub(crate) fn end_direction(&self) -> Option<(f64, f64)> {
+        match self {
+            Edge::Arc(start, mid, end) => {
+                let (center, _, sweep) = arc_geometry(*start, *mid, *end)?;
+                let radial = *end - center;
+                let tangent = (
+                    -radial.y.m() * sweep.signum(),
+                    radial.x.m() * sweep.signum(),
+                );
+                normalize(tangent)
+            }
+            Edge::Bezier(points) => {
+                let end = points.last()?;
+                let previous = points.iter().rev().find(|point| *point != end)?;
+                let diff = *end - *previous;
+                normalize((diff.x.m(), diff.y.m()))
+            }
+            Edge::Circle(_, _) => None,
+            Edge::Ellipse(..) => None,
+            Edge::Line(start, end) => {
+                let diff = *end - *start;
+                normalize((diff.x.m(), diff.y.m()))
+            }
+            Edge::Spline(_) => {
+                let edge = self.curve_to_occt(&Plane::xy())?;
+                let curve = ffi::BRepAdaptor_Curve_ctor(&edge);
+                let last = curve.LastParameter();
+                let step = (last - curve.FirstParameter()) * 1e-6;
+                let end = ffi::BRepAdaptor_Curve_value(&curve, last);
+                let before = ffi::BRepAdaptor_Curve_value(&curve, last - step);
+                normalize((end.X() - before.X(), end.Y() - before.Y()))
+            }
+        }
+    }
+
+    /// Return the direction in which the `Edge` is heading at its start as a unit vector in
+    /// meters.
+    ///
+    /// Returns `None` for closed edges and edges without length.
+    pub(crate) fn start_direction(&self) -> Option<(f64, f64)> {
+        let (x, y) = self.reversed().end_direction()?;
+        Some((-x, -y))
+    }
+
+    /// Return the same `Edge` traversed from its end to its start.
+    fn reversed(&self) -> Edge {
+        match self {
+            Edge::Arc(start, mid, end) => Edge::Arc(*end, *mid, *start),
+            Edge::Bezier(points) => Edge::Bezier(points.iter().rev().copied().collect()),
+            Edge::Circle(center, radius) => Edge::Circle(*center, *radius),
+            Edge::Ellipse(center, x, y) => Edge::Ellipse(*center, *x, *y),
+            Edge::Line(start, end) => Edge::Line(*end, *start),
+            Edge::Spline(points) => Edge::Spline(points.iter().rev().copied().collect()),
+        }
+    }
+
     pub(crate) fn to_occt(&self, plane: &Plane) -> Option<UniquePtr<ffi::TopoDS_Edge>> {
-        if self.len() == Length::zero() {
+        // curves reject degenerate input while they are constructed, so measuring them up front
+        // would construct them twice
+        let is_curve = matches!(self, Edge::Bezier(_) | Edge::Ellipse(..) | Edge::Spline(_));
+        if !is_curve && self.len() == Length::zero() {
             return None;
         }
         match self {
+            Edge::Arc(start, mid, end) => {
+                let make_arc = ffi::GC_MakeArcOfCircle_point_point_point(
+                    &start.to_3d(plane).to_occt_point(),
+                    &mid.to_3d(plane).to_occt_point(),
+                    &end.to_3d(plane).to_occt_point(),
+                );
+                let curve = ffi::new_HandleGeomCurve_from_HandleGeom_TrimmedCurve(
+                    &ffi::GC_MakeArcOfCircle_Value(&make_arc),
+                );
+                let mut constructor = ffi::BRepBuilderAPI_MakeEdge_HandleGeomCurve(&curve);
+                Some(ffi::TopoDS_Edge_to_owned(constructor.pin_mut().Edge()))
+            }
+            Edge::Bezier(_) | Edge::Ellipse(..) | Edge::Spline(_) => self.curve_to_occt(plane),
             Edge::Circle(center, radius) => {
                 let axis = Axis {
                     origin: center.to_3d(plane),
@@ -80,4 +223,247 @@ impl Edge {
             }
         }
     }
+
+    /// Construct the OpenCascade edge of a `Bezier`, `Ellipse` or `Spline`.
+    ///
+    /// Returns `None` if the control points or radii do not describe a curve with a length.
+    fn curve_to_occt(&self, plane: &Plane) -> Option<UniquePtr<ffi::TopoDS_Edge>> {
+        match self {
+            Edge::Bezier(points) => {
+                if points.len() < 2
+                    || points.len() > MAX_BEZIER_POINTS
+                    || points.iter().all(|point| *point == points[0])
+                {
+                    return None;
+                }
+                let mut poles = ffi::TColgp_Array1OfPnt_ctor(1, points.len() as i32);
+                for (index, point) in points.iter().enumerate() {
+                    poles
+                        .pin_mut()
+                        .SetValue(index as i32 + 1, &point.to_3d(plane).to_occt_point());
+                }
+                let bezier = ffi::Geom_BezierCurve_ctor(&poles);
+                let curve = ffi::new_HandleGeomCurve_from_HandleGeom_BezierCurve(&bezier);
+                let mut constructor = ffi::BRepBuilderAPI_MakeEdge_HandleGeomCurve(&curve);
+                Some(ffi::TopoDS_Edge_to_owned(constructor.pin_mut().Edge()))
+            }
+            Edge::Ellipse(center, x, y) => {
+                if *x <= Length::zero() || *y <= Length::zero() {
+                    return None;
+                }
+                // OpenCascade requires the major radius to lie on the x-axis of the ellipse
+                let (major_direction, major, minor) = if x >= y {
+                    (plane.x_axis(), x, y)
+                } else {
+                    (plane.y_axis(), y, x)
+                };
+                let mut axes = ffi::gp_Ax2_ctor(
+                    &center.to_3d(plane).to_occt_point(),
+                    &plane.normal().to_occt_dir(),
+                );
+                axes.pin_mut().SetXDirection(&major_direction.to_occt_dir());
+                let ellipse = ffi::gp_Elips_ctor(&axes, major.m(), minor.m());
+                let mut constructor = ffi::BRepBuilderAPI_MakeEdge_ellipse(&ellipse);
+                Some(ffi::TopoDS_Edge_to_owned(constructor.pin_mut().Edge()))
+            }
+            Edge::Spline(points) => {
+                let mut distinct_points = points.clone();
+                distinct_points.dedup();
+                if distinct_points.len() < 2 {
+                    return None;
+                }
+                let mut interpolation_points =
+                    ffi::TColgp_HArray1OfPnt_ctor(1, distinct_points.len() as i32);
+                for (index, point) in distinct_points.iter().enumerate() {
+                    interpolation_points
+                        .pin_mut()
+                        .SetValue(index as i32 + 1, &point.to_3d(plane).to_occt_point());
+                }
+                let mut interpolate =
+                    ffi::GeomAPI_Interpolate_ctor(&interpolation_points, false, 1e-7);
+                interpolate.pin_mut().Perform();
+                if !interpolate.IsDone() {
+                    return None;
+                }
+                let curve = ffi::new_HandleGeomCurve_from_HandleGeom_BSplineCurve(
+                    &ffi::GeomAPI_Interpolate_Curve(&interpolate),
+                );
+                let mut constructor = ffi::BRepBuilderAPI_MakeEdge_HandleGeomCurve(&curve);
+                Some(ffi::TopoDS_Edge_to_owned(constructor.pin_mut().Edge()))
+            }
+            _ => self.to_occt(plane),
+        }
+    }
+}
+
+/// Return the center, radius and signed sweep angle in radians of the arc that starts at a point,
+/// passes through a second one and ends at a third one.
+///
+/// Positive sweep angles describe counterclockwise arcs. Returns `None` if the points are
+/// collinear or coincide.
+pub(crate) fn arc_geometry(
+    start: Point2D,
+    mid: Point2D,
+    end: Point2D,
+) -> Option<(Point2D, Length, f64)> {
+    let (ax, ay) = (start.x.m(), start.y.m());
+    let (bx, by) = (mid.x.m(), mid.y.m());
+    let (cx, cy) = (end.x.m(), end.y.m());
+
+    let d = 2. * (ax * (by - cy) + bx * (cy - ay) + cx * (ay - by));
+    if d.abs() < 1e-12 {
+        return None;
+    }
+    let a2 = ax.powi(2) + ay.powi(2);
+    let b2 = bx.powi(2) + by.powi(2);
+    let c2 = cx.powi(2) + cy.powi(2);
+    let center = Point2D::from_m(
+        (a2 * (by - cy) + b2 * (cy - ay) + c2 * (ay - by)) / d,
+        (a2 * (cx - bx) + b2 * (ax - cx) + c2 * (bx - ax)) / d,
+    );
+    let radius = (start - center).distance_to_origin();
+
+    let angle_of = |point: Point2D| {
+        let diff = point - center;
+        diff.y.m().atan2(diff.x.m())
+    };
+    let start_angle = angle_of(start);
+    let to_mid = (angle_of(mid) - start_angle).rem_euclid(f64::consts::TAU);
+    let to_end = (angle_of(end) - start_angle).rem_euclid(f64::consts::TAU);
+
+    let sweep = if to_mid < to_end {
+        to_end
+    } else {
+        to_end - f64::consts::TAU
+    };
+    Some((center, radius, sweep))
+}
+
+fn normalize(vector: (f64, f64)) -> Option<(f64, f64)> {
+    let magnitude = f64::sqrt(vector.0.powi(2) + vector.1.powi(2));
+    if magnitude == 0. {
+        return None;
+    }
+    Some((vector.0 / magnitude, vector.1 / magnitude))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arc_len_clockwise() {
+        let edge = Edge::Arc(
+            Point2D::from_m(0., 1.),
+            Point2D::from_m(1., 0.),
+            Point2D::from_m(0., -1.),
+        );
+        assert!((edge.len().m() - f64::consts::PI).abs() < 1e-9)
+    }
+
+    #[test]
+    fn arc_len_major() {
+        let edge = Edge::Arc(
+            Point2D::from_m(1., 0.),
+            Point2D::from_m(-1., 0.),
+            Point2D::from_m(0., -1.),
+        );
+        assert!((edge.len().m() - 1.5 * f64::consts::PI).abs() < 1e-9)
+    }
+
+    #[test]
+    fn arc_len_collinear() {
+        let edge = Edge::Arc(
+            Point2D::from_m(0., 0.),
+            Point2D::from_m(1., 0.),
+            Point2D::from_m(2., 0.),
+        );
+        assert_eq!(edge.len(), Length::zero())
+    }
+
+    #[test]
+    fn bezier_len_straight() {
+        let edge = Edge::Bezier(vec![
+            Point2D::from_m(0., 0.),
+            Point2D::from_m(1., 0.),
+            Point2D::from_m(2., 0.),
+            Point2D::from_m(3., 0.),
+        ]);
+        assert!((edge.len().m() - 3.).abs() < 1e-9)
+    }
+
+    #[test]
+    fn bezier_len_single_point() {
+        let edge = Edge::Bezier(vec![Point2D::from_m(1., 1.), Point2D::from_m(1., 1.)]);
+        assert_eq!(edge.len(), Length::zero())
+    }
+
+    #[test]
+    fn spline_len_through_collinear_points() {
+        let edge = Edge::Spline(vec![
+            Point2D::from_m(0., 0.),
+            Point2D::from_m(1., 1.),
+            Point2D::from_m(2., 2.),
+        ]);
+        assert!((edge.len().m() - f64::sqrt(8.)).abs() < 1e-6)
+    }
+
+    #[test]
+    fn spline_start_and_end() {
+        let edge = Edge::Spline(vec![
+            Point2D::from_m(0., 0.),
+            Point2D::from_m(1., 2.),
+            Point2D::from_m(3., 1.),
+        ]);
+        assert_eq!(edge.start(), Point2D::from_m(0., 0.));
+        assert_eq!(edge.end(), Point2D::from_m(3., 1.));
+    }
+
+    #[test]
+    fn ellipse_len_circle() {
+        let edge = Edge::Ellipse(Point2D::origin(), Length::from_m(2.), Length::from_m(2.));
+        assert!((edge.len().m() - 4. * f64::consts::PI).abs() < 1e-6)
+    }
+
+    #[test]
+    fn ellipse_len_flat() {
+        let edge = Edge::Ellipse(Point2D::origin(), Length::from_m(2.), Length::zero());
+        assert_eq!(edge.len(), Length::zero())
+    }
+
+    #[test]
+    fn line_start_direction() {
+        let edge = Edge::Line(Point2D::from_m(1., 1.), Point2D::from_m(1., 3.));
+        assert_eq!(edge.start_direction(), Some((0., 1.)))
+    }
+
+    #[test]
+    fn spline_end_direction_straight() {
+        let edge = Edge::Spline(vec![
+            Point2D::from_m(0., 0.),
+            Point2D::from_m(1., 0.),
+            Point2D::from_m(2., 0.),
+        ]);
+        let (x, y) = edge.end_direction().unwrap();
+        assert!((x - 1.).abs() < 1e-6 && y.abs() < 1e-6);
+    }
+
+    #[test]
+    fn arc_end_direction() {
+        let ccw = Edge::Arc(
+            Point2D::from_m(1., 0.),
+            Point2D::from_m(0., 1.),
+            Point2D::from_m(-1., 0.),
+        );
+        let (x, y) = ccw.end_direction().unwrap();
+        assert!(x.abs() < 1e-9 && (y + 1.).abs() < 1e-9);
+
+        let cw = Edge::Arc(
+            Point2D::from_m(-1., 0.),
+            Point2D::from_m(0., 1.),
+            Point2D::from_m(1., 0.),
+        );
+        let (x, y) = cw.end_direction().unwrap();
+        assert!(x.abs() < 1e-9 && (y + 1.).abs() < 1e-9);
+    }
 }
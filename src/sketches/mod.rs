@@ -1,8 +1,12 @@
 mod edge;
+mod offset;
 mod path;
 pub mod primitives;
 mod sketch;
 
 pub use edge::Edge;
-pub use path::Path;
+pub use offset::JoinStyle;
+pub use path::{ArcDirection, EndCap, Path, ThickenSide};
 pub use sketch::Sketch;
+
+pub(crate) use sketch::unique_edges;
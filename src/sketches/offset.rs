@@ -0,0 +1,9 @@
+/// The shape of the corners that are created when offsetting a `Sketch` outwards.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum JoinStyle {
+    /// Extend the adjacent edges until they intersect in a sharp corner.
+    Miter,
+
+    /// Connect the adjacent edges with an arc around the original corner.
+    Round,
+}
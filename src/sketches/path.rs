@@ -1,6 +1,44 @@
-use crate::Point2D;
+use core::f64;
 
-use super::{Edge, Sketch};
+use cxx::UniquePtr;
+use opencascade_sys::ffi;
+
+use crate::{Angle, Error, Length, Plane, Point2D, parts::points_along_edges};
+
+use super::{Edge, Sketch, edge::arc_geometry, primitives::Circle};
+
+/// The direction in which an arc turns from its start to its end.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum ArcDirection {
+    /// The arc turns in the mathematically negative direction.
+    Clockwise,
+
+    /// The arc turns in the mathematically positive direction.
+    CounterClockwise,
+}
+
+/// The shape of the ends of a thickened `Path`.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum EndCap {
+    /// The thickened path ends with a straight edge perpendicular to the path.
+    Flat,
+
+    /// The thickened path ends with a semicircle.
+    Round,
+}
+
+/// The side of a `Path` on which material is added when thickening it.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum ThickenSide {
+    /// The material is distributed evenly on both sides of the path.
+    Center,
+
+    /// The material is added to the left of the path when looking in its direction.
+    Left,
+
+    /// The material is added to the right of the path when looking in its direction.
+    Right,
+}
 
 /// A continuous series of edges (i.e. lines, arcs, ...).
 #[derive(Debug, PartialEq, Clone)]
@@ -35,7 +73,299 @@ impl Path {
     /// assert_eq!(path.end(), Point2D::from_m(3., 4.))
     /// ```
     pub fn line_to(&self, point: Point2D) -> Self {
-        self.add_edge(Edge::Line(self.cursor, point))
+        self.push_edge(Edge::Line(self.cursor, point))
+    }
+
+    /// Add a line to the end of this `Path` that is offset from the current end by given distances.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{length, Path, Point2D};
+    ///
+    /// let path = Path::at(Point2D::from_m(1., 2.)).line_by(length!(3 m), length!(-1 m));
+    /// assert_eq!(path.end(), Point2D::from_m(4., 1.))
+    /// ```
+    pub fn line_by(&self, dx: Length, dy: Length) -> Self {
+        self.line_to(self.cursor + Point2D::new(dx, dy))
+    }
+
+    /// Add a line with a given length to the end of this `Path` that points in the direction of an
+    /// angle measured counterclockwise from the x-axis.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{angle, length, Path, Point2D};
+    ///
+    /// let path = Path::at(Point2D::origin()).line_polar(length!(2 m), angle!(90 deg));
+    /// assert!((path.end() - Point2D::from_m(0., 2.)).distance_to_origin().m() < 1e-9)
+    /// ```
+    pub fn line_polar(&self, length: Length, angle: Angle) -> Self {
+        self.line_by(length * angle.rad().cos(), length * angle.rad().sin())
+    }
+
+    /// Add a horizontal line to the end of this `Path` that ends at a given x-coordinate.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{length, Path, Point2D};
+    ///
+    /// let path = Path::at(Point2D::from_m(1., 2.)).horizontal_to(length!(5 m));
+    /// assert_eq!(path.end(), Point2D::from_m(5., 2.))
+    /// ```
+    pub fn horizontal_to(&self, x: Length) -> Self {
+        self.line_to(Point2D::new(x, self.cursor.y))
+    }
+
+    /// Add a horizontal line to the end of this `Path` with a given signed length.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{length, Path, Point2D};
+    ///
+    /// let path = Path::at(Point2D::from_m(1., 2.)).horizontal_by(length!(-3 m));
+    /// assert_eq!(path.end(), Point2D::from_m(-2., 2.))
+    /// ```
+    pub fn horizontal_by(&self, dx: Length) -> Self {
+        self.line_by(dx, Length::zero())
+    }
+
+    /// Add a vertical line to the end of this `Path` that ends at a given y-coordinate.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{length, Path, Point2D};
+    ///
+    /// let path = Path::at(Point2D::from_m(1., 2.)).vertical_to(length!(5 m));
+    /// assert_eq!(path.end(), Point2D::from_m(1., 5.))
+    /// ```
+    pub fn vertical_to(&self, y: Length) -> Self {
+        self.line_to(Point2D::new(self.cursor.x, y))
+    }
+
+    /// Add a vertical line to the end of this `Path` with a given signed length.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{length, Path, Point2D};
+    ///
+    /// let path = Path::at(Point2D::from_m(1., 2.)).vertical_by(length!(3 m));
+    /// assert_eq!(path.end(), Point2D::from_m(1., 5.))
+    /// ```
+    pub fn vertical_by(&self, dy: Length) -> Self {
+        self.line_by(Length::zero(), dy)
+    }
+
+    /// Add a circular arc to the end of this `Path` that passes through a point and ends at
+    /// another.
+    ///
+    /// If the three points of the arc are collinear or coincide, an `Err(Error::Arc)` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Path, Point2D};
+    ///
+    /// let path = Path::at(Point2D::from_m(1., 0.))
+    ///     .arc_through(Point2D::from_m(0., 1.), Point2D::from_m(-1., 0.))
+    ///     .unwrap();
+    /// assert_eq!(path.end(), Point2D::from_m(-1., 0.))
+    /// ```
+    pub fn arc_through(&self, mid: Point2D, end: Point2D) -> Result<Self, Error> {
+        if arc_geometry(self.cursor, mid, end).is_none() {
+            return Err(Error::Arc(end));
+        }
+        Ok(self.push_edge(Edge::Arc(self.cursor, mid, end)))
+    }
+
+    /// Add a circular arc to the end of this `Path` that turns around a center point in a given
+    /// direction and ends at another point.
+    ///
+    /// If the end point does not have the same distance to the center as the current end of the
+    /// `Path`, or if the arc has no length, an `Err(Error::Arc)` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{ArcDirection, Path, Point2D};
+    ///
+    /// let path = Path::at(Point2D::from_m(1., 0.))
+    ///     .arc_around(
+    ///         Point2D::origin(),
+    ///         Point2D::from_m(0., -1.),
+    ///         ArcDirection::Clockwise,
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(path.end(), Point2D::from_m(0., -1.));
+    /// assert!((path.len().m() - core::f64::consts::FRAC_PI_2).abs() < 1e-9)
+    /// ```
+    pub fn arc_around(
+        &self,
+        center: Point2D,
+        end: Point2D,
+        direction: ArcDirection,
+    ) -> Result<Self, Error> {
+        let radius = (self.cursor - center).distance_to_origin();
+        if radius == Length::zero() || self.cursor == end {
+            return Err(Error::Arc(end));
+        }
+        if ((end - center).distance_to_origin() - radius).m().abs() > 1e-9 {
+            return Err(Error::Arc(end));
+        }
+
+        let angle_of = |point: Point2D| {
+            let diff = point - center;
+            diff.y.m().atan2(diff.x.m())
+        };
+        let start_angle = angle_of(self.cursor);
+        let counter_clockwise_sweep = (angle_of(end) - start_angle).rem_euclid(f64::consts::TAU);
+        let sweep = match direction {
+            ArcDirection::Clockwise => counter_clockwise_sweep - f64::consts::TAU,
+            ArcDirection::CounterClockwise => counter_clockwise_sweep,
+        };
+
+        let mid_angle = start_angle + sweep / 2.;
+        let mid = center + Point2D::new(radius * mid_angle.cos(), radius * mid_angle.sin());
+        Ok(self.push_edge(Edge::Arc(self.cursor, mid, end)))
+    }
+
+    /// Add a circular arc with a given radius to the end of this `Path` that turns in a given
+    /// direction and ends at a point.
+    ///
+    /// Of the two arcs that satisfy these constraints, the shorter one is used. If the radius is
+    /// smaller than half the distance between the current end of the `Path` and the end point, an
+    /// `Err(Error::Arc)` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{ArcDirection, length, Path, Point2D};
+    ///
+    /// let path = Path::at(Point2D::from_m(1., 0.))
+    ///     .arc_with_radius(
+    ///         length!(1 m),
+    ///         Point2D::from_m(-1., 0.),
+    ///         ArcDirection::CounterClockwise,
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(path.end(), Point2D::from_m(-1., 0.));
+    /// assert!((path.len().m() - core::f64::consts::PI).abs() < 1e-9)
+    /// ```
+    pub fn arc_with_radius(
+        &self,
+        radius: Length,
+        end: Point2D,
+        direction: ArcDirection,
+    ) -> Result<Self, Error> {
+        let chord = end - self.cursor;
+        let chord_len = chord.distance_to_origin();
+        let half_chord = chord_len.m() / 2.;
+        if chord_len == Length::zero() || radius.m() < half_chord - 1e-9 {
+            return Err(Error::Arc(end));
+        }
+
+        let height = f64::sqrt((radius.m().powi(2) - half_chord.powi(2)).max(0.));
+        let left = Point2D::new(chord.y * -1., chord.x) / chord_len.m();
+        let chord_mid = self.cursor + chord / 2.;
+        let center = match direction {
+            ArcDirection::Clockwise => chord_mid - left * height,
+            ArcDirection::CounterClockwise => chord_mid + left * height,
+        };
+        self.arc_around(center, end, direction)
+    }
+
+    /// Add a circular arc to the end of this `Path` that continues tangentially from the last
+    /// edge and ends at a point.
+    ///
+    /// If the `Path` has no edges yet or if the end point lies on the straight continuation of the
+    /// last edge, an `Err(Error::Arc)` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Path, Point2D};
+    ///
+    /// let path = Path::at(Point2D::origin())
+    ///     .line_to(Point2D::from_m(1., 0.))
+    ///     .tangent_arc_to(Point2D::from_m(1., 2.))
+    ///     .unwrap();
+    /// assert_eq!(path.end(), Point2D::from_m(1., 2.));
+    /// assert!((path.len().m() - 1. - core::f64::consts::PI).abs() < 1e-9)
+    /// ```
+    pub fn tangent_arc_to(&self, end: Point2D) -> Result<Self, Error> {
+        let (tx, ty) = self
+            .edges
+            .last()
+            .and_then(|edge| edge.end_direction())
+            .ok_or(Error::Arc(end))?;
+
+        let diff = end - self.cursor;
+        let (dx, dy) = (diff.x.m(), diff.y.m());
+        let normal_component = -ty * dx + tx * dy;
+        if normal_component.abs() < 1e-12 {
+            return Err(Error::Arc(end));
+        }
+
+        // the center lies on the normal of the tangent with equal distance to both ends
+        let offset = (dx.powi(2) + dy.powi(2)) / (2. * normal_component);
+        let center = self.cursor + Point2D::from_m(-ty * offset, tx * offset);
+        let direction = if offset > 0. {
+            ArcDirection::CounterClockwise
+        } else {
+            ArcDirection::Clockwise
+        };
+        self.arc_around(center, end, direction)
+    }
+
+    /// Add a Bezier curve to the end of this `Path` that is shaped by intermediate control points
+    /// and ends at a point.
+    ///
+    /// One control point results in a quadratic and two control points in a cubic Bezier curve.
+    /// Any other number of control points results in an `Err(Error::Bezier)` with the total
+    /// number of points of the curve.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Error, Path, Point2D};
+    ///
+    /// let path = Path::at(Point2D::origin())
+    ///     .bezier_to(
+    ///         &[Point2D::from_m(0., 1.), Point2D::from_m(1., 1.)],
+    ///         Point2D::from_m(1., 0.),
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(path.end(), Point2D::from_m(1., 0.));
+    /// assert_eq!(
+    ///     Path::at(Point2D::origin()).bezier_to(&[], Point2D::from_m(1., 0.)),
+    ///     Err(Error::Bezier(2))
+    /// );
+    /// ```
+    pub fn bezier_to(&self, controls: &[Point2D], end: Point2D) -> Result<Self, Error> {
+        if !(1..=2).contains(&controls.len()) {
+            return Err(Error::Bezier(controls.len() + 2));
+        }
+        let mut points = vec![self.cursor];
+        points.extend_from_slice(controls);
+        points.push(end);
+        Ok(self.push_edge(Edge::Bezier(points)))
+    }
+
+    /// Add a smooth B-spline curve to the end of this `Path` that passes through a series of
+    /// points and ends at the last one.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Path, Point2D};
+    ///
+    /// let path = Path::at(Point2D::origin()).spline_through(&[
+    ///     Point2D::from_m(1., 1.),
+    ///     Point2D::from_m(2., 0.),
+    ///     Point2D::from_m(3., 1.),
+    /// ]);
+    /// assert_eq!(path.end(), Point2D::from_m(3., 1.))
+    /// ```
+    pub fn spline_through(&self, points: &[Point2D]) -> Self {
+        if points.is_empty() {
+            return self.clone();
+        }
+        let mut spline_points = vec![self.cursor];
+        spline_points.extend_from_slice(points);
+        self.push_edge(Edge::Spline(spline_points))
     }
 
     /// Connect the end of this `Path` to its start with a straight line and return the resulting `Sketch`.
@@ -47,6 +377,69 @@ impl Path {
         }
     }
 
+    /// Turn this open `Path` into a `Sketch` by giving it a thickness.
+    ///
+    /// The walls of the resulting `Sketch` follow the path at a constant distance. Depending on
+    /// the `ThickenSide`, the path becomes the centerline or one of the boundaries of the
+    /// `Sketch`. If the path has no edges, an empty `Sketch` is returned. If the thickness is not
+    /// positive, an `Err(Error::Thicken)` is returned. The same error occurs when the `Sketch` is
+    /// built if the walls can not be constructed along the path.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{EndCap, Error, length, Path, Point2D, ThickenSide};
+    ///
+    /// let path = Path::at(Point2D::from_m(0., 2.))
+    ///     .line_to(Point2D::origin())
+    ///     .line_to(Point2D::from_m(3., 0.));
+    /// let bracket = path
+    ///     .thicken(length!(1 m), ThickenSide::Left, EndCap::Flat)
+    ///     .unwrap();
+    /// assert!((bracket.area() - 4.).abs() < 1e-6);
+    /// assert_eq!(
+    ///     path.thicken(length!(0 m), ThickenSide::Left, EndCap::Flat),
+    ///     Err(Error::Thicken(length!(0 m)))
+    /// );
+    /// ```
+    pub fn thicken(
+        &self,
+        thickness: Length,
+        side: ThickenSide,
+        cap: EndCap,
+    ) -> Result<Sketch, Error> {
+        let (first_edge, last_edge) = match (self.edges.first(), self.edges.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return Ok(Sketch::empty()),
+        };
+        if thickness <= Length::zero() {
+            return Err(Error::Thicken(thickness));
+        }
+
+        let (left, right) = match side {
+            ThickenSide::Center => (thickness / 2., thickness / 2.),
+            ThickenSide::Left => (thickness, Length::zero()),
+            ThickenSide::Right => (Length::zero(), thickness),
+        };
+        let stroke = Sketch::from_stroke(self.edges.clone(), left, right);
+
+        Ok(match cap {
+            EndCap::Flat => stroke,
+            EndCap::Round => [
+                (first_edge.start(), first_edge.start_direction()),
+                (last_edge.end(), last_edge.end_direction()),
+            ]
+            .into_iter()
+            .fold(stroke, |sketch, (point, direction)| match direction {
+                Some((x, y)) => {
+                    let left_normal = Point2D::from_m(-y, x);
+                    let center = point + left_normal * ((left - right).m() / 2.);
+                    sketch.add(&Circle::from_radius(thickness / 2.).move_to(center))
+                }
+                None => sketch,
+            }),
+        })
+    }
+
     /// Return the starting point of the `Path`.
     ///
     /// If the path does not have any edges, the cursor is returned.
@@ -89,11 +482,70 @@ impl Path {
         }
     }
 
-    fn add_edge(&self, edge: Edge) -> Self {
+    /// Return the combined length of all edges of the `Path`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{length, Path, Point2D};
+    ///
+    /// let path = Path::at(Point2D::origin())
+    ///     .line_to(Point2D::from_m(3., 0.))
+    ///     .line_to(Point2D::from_m(3., 4.));
+    /// assert_eq!(path.len(), length!(7 m))
+    /// ```
+    pub fn len(&self) -> Length {
+        self.edges
+            .iter()
+            .fold(Length::zero(), |total, edge| total + edge.len())
+    }
+
+    /// Add an `Edge` to the end of this `Path`.
+    ///
+    /// If the `Edge` does not start where the `Path` currently ends, an
+    /// `Err(Error::DiscontinuousPath)` with both points is returned. A `Bezier` or `Spline` with
+    /// too few or too many points results in an `Err(Error::Bezier)` or `Err(Error::Spline)`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Edge, Error, Path, Point2D};
+    ///
+    /// let path = Path::at(Point2D::origin());
+    /// assert!(path.add_edge(Edge::Line(Point2D::origin(), Point2D::from_m(1., 0.))).is_ok());
+    /// assert_eq!(
+    ///     path.add_edge(Edge::Line(Point2D::from_m(1., 1.), Point2D::from_m(2., 0.))),
+    ///     Err(Error::DiscontinuousPath(Point2D::origin(), Point2D::from_m(1., 1.)))
+    /// );
+    /// ```
+    pub fn add_edge(&self, edge: Edge) -> Result<Self, Error> {
+        edge.validate()?;
         if edge.start() != self.end() {
-            panic!("path is not continuous");
+            return Err(Error::DiscontinuousPath(self.end(), edge.start()));
         }
+        Ok(self.push_edge(edge))
+    }
+
+    /// Return a number of points that are spaced evenly along this `Path`.
+    ///
+    /// The first point is the start of the path. On an open path, the last point is its end; on a
+    /// closed path, the points are spread around the whole loop without repeating the start.
+    pub(crate) fn points_along(&self, count: usize) -> Result<Vec<Point2D>, Error> {
+        let occt_edges: Vec<UniquePtr<ffi::TopoDS_Edge>> = self
+            .edges
+            .iter()
+            .filter_map(|edge| edge.to_occt(&Plane::xy()))
+            .collect();
 
+        if occt_edges.is_empty() {
+            return Err(Error::EmptyPath);
+        }
+        Ok(points_along_edges(&occt_edges, count)
+            .into_iter()
+            .map(|point| Point2D::new(point.x, point.y))
+            .collect())
+    }
+
+    /// Add an `Edge` that starts at the cursor to the end of this `Path`.
+    fn push_edge(&self, edge: Edge) -> Self {
         let new_cursor = edge.end();
         let mut new_edges = self.edges.clone();
         new_edges.push(edge);
@@ -104,3 +556,239 @@ impl Path {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Rectangle, length};
+
+    #[test]
+    fn arc_around_counter_clockwise_major() {
+        let path = Path::at(Point2D::from_m(1., 0.)).arc_around(
+            Point2D::origin(),
+            Point2D::from_m(0., -1.),
+            ArcDirection::CounterClockwise,
+        );
+        assert!((path.unwrap().len().m() - 1.5 * f64::consts::PI).abs() < 1e-9)
+    }
+
+    #[test]
+    fn arc_through_collinear() {
+        assert_eq!(
+            Path::at(Point2D::origin())
+                .arc_through(Point2D::from_m(1., 0.), Point2D::from_m(2., 0.)),
+            Err(Error::Arc(Point2D::from_m(2., 0.)))
+        )
+    }
+
+    #[test]
+    fn arc_around_end_not_on_circle() {
+        assert_eq!(
+            Path::at(Point2D::from_m(1., 0.)).arc_around(
+                Point2D::origin(),
+                Point2D::from_m(0., 2.),
+                ArcDirection::Clockwise,
+            ),
+            Err(Error::Arc(Point2D::from_m(0., 2.)))
+        )
+    }
+
+    #[test]
+    fn arc_with_radius_too_small() {
+        assert_eq!(
+            Path::at(Point2D::origin()).arc_with_radius(
+                length!(0.5 m),
+                Point2D::from_m(2., 0.),
+                ArcDirection::Clockwise,
+            ),
+            Err(Error::Arc(Point2D::from_m(2., 0.)))
+        )
+    }
+
+    #[test]
+    fn tangent_arc_without_previous_edge() {
+        assert_eq!(
+            Path::at(Point2D::origin()).tangent_arc_to(Point2D::from_m(1., 1.)),
+            Err(Error::Arc(Point2D::from_m(1., 1.)))
+        )
+    }
+
+    #[test]
+    fn add_edge_continuous() {
+        let path = Path::at(Point2D::origin())
+            .add_edge(Edge::Line(Point2D::origin(), Point2D::from_m(1., 0.)))
+            .unwrap();
+        assert_eq!(
+            path,
+            Path::at(Point2D::origin()).line_to(Point2D::from_m(1., 0.))
+        )
+    }
+
+    #[test]
+    fn add_edge_discontinuous() {
+        let path = Path::at(Point2D::origin()).line_to(Point2D::from_m(1., 0.));
+        assert_eq!(
+            path.add_edge(Edge::Line(Point2D::from_m(2., 0.), Point2D::from_m(3., 0.))),
+            Err(Error::DiscontinuousPath(
+                Point2D::from_m(1., 0.),
+                Point2D::from_m(2., 0.)
+            ))
+        )
+    }
+
+    #[test]
+    fn add_edge_without_points() {
+        let path = Path::at(Point2D::origin());
+        assert_eq!(path.add_edge(Edge::Bezier(vec![])), Err(Error::Bezier(0)));
+        assert_eq!(path.add_edge(Edge::Spline(vec![])), Err(Error::Spline(0)));
+    }
+
+    #[test]
+    fn bezier_to_too_many_controls() {
+        let controls = [Point2D::from_m(1., 1.); 3];
+        assert_eq!(
+            Path::at(Point2D::origin()).bezier_to(&controls, Point2D::from_m(2., 0.)),
+            Err(Error::Bezier(5))
+        )
+    }
+
+    #[test]
+    fn tangent_arc_after_bezier() {
+        let path = Path::at(Point2D::origin())
+            .bezier_to(&[Point2D::from_m(1., 0.)], Point2D::from_m(2., 0.))
+            .unwrap()
+            .tangent_arc_to(Point2D::from_m(2., 2.))
+            .unwrap();
+        assert!((path.len().m() - 2. - f64::consts::PI).abs() < 1e-6)
+    }
+
+    #[test]
+    fn spline_through_no_points() {
+        let path = Path::at(Point2D::from_m(1., 1.)).spline_through(&[]);
+        assert_eq!(path, Path::at(Point2D::from_m(1., 1.)))
+    }
+
+    #[test]
+    fn closed_bezier_area() {
+        let sketch = Path::at(Point2D::origin())
+            .bezier_to(&[Point2D::from_m(1., 2.)], Point2D::from_m(2., 0.))
+            .unwrap()
+            .close();
+        assert!((sketch.area() - 4. / 3.).abs() < 1e-6)
+    }
+
+    #[test]
+    fn relative_commands_close_square() {
+        let sketch = Path::at(Point2D::origin())
+            .horizontal_by(length!(2 m))
+            .vertical_to(length!(2 m))
+            .line_by(length!(-2 m), Length::zero())
+            .close();
+        assert_eq!(
+            sketch,
+            Rectangle::from_corners(Point2D::origin(), Point2D::from_m(2., 2.))
+        )
+    }
+
+    #[test]
+    fn line_polar_equilateral_triangle() {
+        let sketch = Path::at(Point2D::origin())
+            .line_polar(length!(1 m), Angle::zero())
+            .line_polar(length!(1 m), Angle::from_deg(120))
+            .close();
+        assert!((sketch.area() - f64::sqrt(3.) / 4.).abs() < 1e-9)
+    }
+
+    #[test]
+    fn thicken_line_center_flat() {
+        let sketch = Path::at(Point2D::origin())
+            .line_to(Point2D::from_m(2., 0.))
+            .thicken(length!(1 m), ThickenSide::Center, EndCap::Flat)
+            .unwrap();
+        assert_eq!(
+            sketch,
+            Rectangle::from_corners(Point2D::from_m(0., -0.5), Point2D::from_m(2., 0.5))
+        )
+    }
+
+    #[test]
+    fn thicken_line_right_round() {
+        let sketch = Path::at(Point2D::origin())
+            .line_to(Point2D::from_m(2., 0.))
+            .thicken(length!(1 m), ThickenSide::Right, EndCap::Round)
+            .unwrap();
+        let expected = Rectangle::from_corners(Point2D::from_m(0., -1.), Point2D::from_m(2., 0.))
+            .add(&Circle::from_radius(length!(0.5 m)).move_to(Point2D::from_m(0., -0.5)))
+            .add(&Circle::from_radius(length!(0.5 m)).move_to(Point2D::from_m(2., -0.5)));
+        assert!((sketch.area() - expected.area()).abs() < 1e-6)
+    }
+
+    #[test]
+    fn thicken_arc() {
+        let sketch = Path::at(Point2D::from_m(2., 0.))
+            .arc_through(Point2D::from_m(0., 2.), Point2D::from_m(-2., 0.))
+            .unwrap()
+            .thicken(length!(1 m), ThickenSide::Center, EndCap::Flat)
+            .unwrap();
+        let expected_area = f64::consts::PI / 2. * (2.5_f64.powi(2) - 1.5_f64.powi(2));
+        assert!((sketch.area() - expected_area).abs() < 1e-6)
+    }
+
+    #[test]
+    fn thicken_empty() {
+        assert_eq!(
+            Path::at(Point2D::origin()).thicken(length!(1 m), ThickenSide::Center, EndCap::Round),
+            Ok(Sketch::empty())
+        );
+    }
+
+    #[test]
+    fn thicken_non_positive() {
+        let path = Path::at(Point2D::origin()).line_to(Point2D::from_m(1., 0.));
+        assert_eq!(
+            path.thicken(Length::zero(), ThickenSide::Center, EndCap::Round),
+            Err(Error::Thicken(Length::zero()))
+        );
+        assert_eq!(
+            path.thicken(length!(-1 m), ThickenSide::Left, EndCap::Flat),
+            Err(Error::Thicken(length!(-1 m)))
+        );
+    }
+
+    #[test]
+    fn rounded_slot() -> Result<(), Error> {
+        let slot = Path::at(Point2D::from_m(0., -1.))
+            .line_to(Point2D::from_m(2., -1.))
+            .tangent_arc_to(Point2D::from_m(2., 1.))?
+            .line_to(Point2D::from_m(0., 1.))
+            .tangent_arc_to(Point2D::from_m(0., -1.))?
+            .close();
+        let expected = Rectangle::from_corners(Point2D::from_m(0., -1.), Point2D::from_m(2., 1.))
+            .add(&Circle::from_radius(length!(1 m)))
+            .add(&Circle::from_radius(length!(1 m)).move_to(Point2D::from_m(2., 0.)));
+        assert!((slot.area() - expected.area()).abs() < 1e-6);
+        Ok(())
+    }
+
+    #[test]
+    fn points_along_open_path() {
+        let points = Path::at(Point2D::origin())
+            .line_to(Point2D::from_m(2., 0.))
+            .line_to(Point2D::from_m(2., 2.))
+            .points_along(5)
+            .unwrap();
+        let expected = [(0., 0.), (1., 0.), (2., 0.), (2., 1.), (2., 2.)];
+        assert_eq!(points.len(), expected.len());
+        for (point, (x, y)) in points.into_iter().zip(expected) {
+            assert!((point - Point2D::from_m(x, y)).distance_to_origin().m() < 1e-9)
+        }
+    }
+
+    #[test]
+    fn points_along_empty_path() {
+        assert_eq!(
+            Path::at(Point2D::origin()).points_along(2),
+            Err(Error::EmptyPath)
+        )
+    }
+}
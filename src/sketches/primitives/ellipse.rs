@@ -0,0 +1,72 @@
+use crate::{Edge, Length, Point2D, Sketch};
+
+/// Builder for an elliptic `Sketch`.
+///
+/// While the `Ellipse` struct itself is not used, its constructor methods like
+/// `Ellipse::from_semi_axes()` can be used to build this primitive `Sketch`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Ellipse;
+impl Ellipse {
+    /// Construct a centered elliptic `Sketch` from its semi-axes in x and y direction.
+    ///
+    /// If either of the semi-axes is not positive, an empty `Sketch` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use core::f64;
+    /// use anvil::{Ellipse, length, Point2D};
+    ///
+    /// let ellipse = Ellipse::from_semi_axes(length!(2 m), length!(1 m));
+    /// assert!((ellipse.area() - 2. * f64::consts::PI).abs() < 1e-6);
+    /// assert_eq!(ellipse.center(), Ok(Point2D::origin()));
+    /// ```
+    pub fn from_semi_axes(x: Length, y: Length) -> Sketch {
+        if x <= Length::zero() || y <= Length::zero() {
+            return Sketch::empty();
+        }
+        Sketch::from_edges(vec![Edge::Ellipse(Point2D::origin(), x, y)])
+    }
+
+    /// Construct a centered elliptic `Sketch` from its full axes in x and y direction.
+    ///
+    /// # Example
+    /// ```rust
+    /// use core::f64;
+    /// use anvil::{Ellipse, length, Point2D};
+    ///
+    /// let ellipse = Ellipse::from_axes(length!(4 m), length!(2 m));
+    /// assert!((ellipse.area() - 2. * f64::consts::PI).abs() < 1e-6);
+    /// assert_eq!(ellipse.center(), Ok(Point2D::origin()));
+    /// ```
+    pub fn from_axes(x: Length, y: Length) -> Sketch {
+        Self::from_semi_axes(x / 2., y / 2.)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::length;
+
+    #[test]
+    fn from_semi_axes_major_on_y() {
+        let ellipse = Ellipse::from_semi_axes(length!(1 m), length!(3 m));
+        assert!((ellipse.area() - 3. * core::f64::consts::PI).abs() < 1e-6)
+    }
+
+    #[test]
+    fn from_semi_axes_empty() {
+        assert_eq!(
+            Ellipse::from_semi_axes(length!(0), length!(1 m)),
+            Sketch::empty()
+        );
+        assert_eq!(
+            Ellipse::from_semi_axes(length!(1 m), length!(0)),
+            Sketch::empty()
+        );
+        assert_eq!(
+            Ellipse::from_semi_axes(length!(-1 m), length!(1 m)),
+            Sketch::empty()
+        );
+    }
+}
@@ -1,5 +1,13 @@
 mod circle;
+mod ellipse;
+mod polygon;
 mod rectangle;
+mod rounded_rectangle;
+mod slot;
 
 pub use circle::Circle;
+pub use ellipse::Ellipse;
+pub use polygon::Polygon;
 pub use rectangle::Rectangle;
+pub use rounded_rectangle::RoundedRectangle;
+pub use slot::Slot;
@@ -0,0 +1,113 @@
+use core::f64;
+
+use crate::{Length, Path, Point2D, Sketch};
+
+/// Builder for a polygonal `Sketch`.
+///
+/// While the `Polygon` struct itself is not used, its constructor methods like
+/// `Polygon::from_circumradius()` can be used to build this primitive `Sketch`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Polygon;
+impl Polygon {
+    /// Construct a centered regular polygon `Sketch` from the radius of the circle through its
+    /// corners and the number of sides.
+    ///
+    /// The first corner lies on the positive x-axis.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{length, Point2D, Polygon};
+    ///
+    /// let square = Polygon::from_circumradius(length!(1 m), 4);
+    /// assert!((square.area() - 2.).abs() < 1e-9);
+    /// assert_eq!(square.center(), Ok(Point2D::origin()));
+    /// ```
+    pub fn from_circumradius(radius: Length, sides: usize) -> Sketch {
+        if sides < 3 || radius <= Length::zero() {
+            return Sketch::empty();
+        }
+        let corners: Vec<Point2D> = (0..sides)
+            .map(|index| {
+                let angle = f64::consts::TAU * index as f64 / sides as f64;
+                Point2D::new(radius * angle.cos(), radius * angle.sin())
+            })
+            .collect();
+        Self::from_points(&corners)
+    }
+
+    /// Construct a centered regular polygon `Sketch` from the radius of the circle that touches
+    /// its sides and the number of sides.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{length, Point2D, Polygon};
+    ///
+    /// let square = Polygon::from_inradius(length!(1 m), 4);
+    /// assert!((square.area() - 4.).abs() < 1e-9);
+    /// assert_eq!(square.center(), Ok(Point2D::origin()));
+    /// ```
+    pub fn from_inradius(radius: Length, sides: usize) -> Sketch {
+        if sides < 3 {
+            return Sketch::empty();
+        }
+        Self::from_circumradius(radius / (f64::consts::PI / sides as f64).cos(), sides)
+    }
+
+    /// Construct a polygon `Sketch` from the locations of its corners.
+    ///
+    /// The last corner is connected to the first one. If less than three corners are given, an
+    /// empty `Sketch` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Point2D, Polygon};
+    ///
+    /// let triangle = Polygon::from_points(&[
+    ///     Point2D::origin(),
+    ///     Point2D::from_m(2., 0.),
+    ///     Point2D::from_m(0., 2.),
+    /// ]);
+    /// assert!((triangle.area() - 2.).abs() < 1e-9);
+    /// ```
+    pub fn from_points(points: &[Point2D]) -> Sketch {
+        if points.len() < 3 {
+            return Sketch::empty();
+        }
+        points[1..]
+            .iter()
+            .fold(Path::at(points[0]), |path, point| path.line_to(*point))
+            .close()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::length;
+
+    #[test]
+    fn from_circumradius_hexagon() {
+        let hexagon = Polygon::from_circumradius(length!(1 m), 6);
+        assert!((hexagon.area() - 3. * f64::sqrt(3.) / 2.).abs() < 1e-9)
+    }
+
+    #[test]
+    fn from_circumradius_empty() {
+        assert_eq!(Polygon::from_circumradius(length!(0), 6), Sketch::empty());
+        assert_eq!(Polygon::from_circumradius(length!(1 m), 2), Sketch::empty());
+    }
+
+    #[test]
+    fn from_inradius_empty() {
+        assert_eq!(Polygon::from_inradius(length!(0), 6), Sketch::empty());
+        assert_eq!(Polygon::from_inradius(length!(1 m), 0), Sketch::empty());
+    }
+
+    #[test]
+    fn from_points_empty() {
+        assert_eq!(
+            Polygon::from_points(&[Point2D::origin(), Point2D::from_m(1., 1.)]),
+            Sketch::empty()
+        )
+    }
+}
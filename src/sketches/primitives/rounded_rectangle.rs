@@ -0,0 +1,127 @@
+use crate::{Edge, Length, Point2D, Rectangle, Sketch};
+
+/// Builder for a rectangular `Sketch` with rounded corners.
+///
+/// While the `RoundedRectangle` struct itself is not used, its constructor methods like
+/// `RoundedRectangle::from_dim()` can be used to build this primitive `Sketch`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RoundedRectangle;
+impl RoundedRectangle {
+    /// Construct a centered rounded rectangular `Sketch` from the x and y dimensions and the
+    /// radius of the corners.
+    ///
+    /// # Example
+    /// ```rust
+    /// use core::f64;
+    /// use anvil::{length, Point2D, RoundedRectangle};
+    ///
+    /// let rect = RoundedRectangle::from_dim(length!(2 m), length!(2 m), length!(0.5 m));
+    /// assert!((rect.area() - (3. + f64::consts::PI / 4.)).abs() < 1e-9);
+    /// assert_eq!(rect.center(), Ok(Point2D::origin()));
+    /// ```
+    pub fn from_dim(x: Length, y: Length, radius: Length) -> Sketch {
+        Self::from_corners(
+            Point2D::new(x * -0.5, y * -0.5),
+            Point2D::new(x * 0.5, y * 0.5),
+            radius,
+        )
+    }
+
+    /// Construct a rounded rectangular `Sketch` from the locations of the corners of its bounding
+    /// rectangle and the radius of the corners.
+    ///
+    /// If the radius is negative or larger than half of the smaller side, an empty `Sketch` is
+    /// returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use core::f64;
+    /// use anvil::{length, Point2D, RoundedRectangle};
+    ///
+    /// let rect = RoundedRectangle::from_corners(
+    ///     Point2D::origin(),
+    ///     Point2D::from_m(2., 2.),
+    ///     length!(0.5 m),
+    /// );
+    /// assert!((rect.area() - (3. + f64::consts::PI / 4.)).abs() < 1e-9);
+    /// ```
+    pub fn from_corners(corner1: Point2D, corner2: Point2D, radius: Length) -> Sketch {
+        let (min_x, max_x) = (corner1.x.min(&corner2.x), corner1.x.max(&corner2.x));
+        let (min_y, max_y) = (corner1.y.min(&corner2.y), corner1.y.max(&corner2.y));
+        let smaller_side = (max_x - min_x).min(&(max_y - min_y));
+        if radius < Length::zero() || radius * 2. > smaller_side {
+            return Sketch::empty();
+        }
+        if radius == Length::zero() {
+            return Rectangle::from_corners(corner1, corner2);
+        }
+
+        // the distance of the arc midpoint to the corner of the bounding rectangle on each axis
+        let inset = radius * (1. - f64::sqrt(0.5));
+        let point = |x: Length, y: Length| Point2D::new(x, y);
+        Sketch::from_edges(vec![
+            Edge::Line(point(min_x + radius, min_y), point(max_x - radius, min_y)),
+            Edge::Arc(
+                point(max_x - radius, min_y),
+                point(max_x - inset, min_y + inset),
+                point(max_x, min_y + radius),
+            ),
+            Edge::Line(point(max_x, min_y + radius), point(max_x, max_y - radius)),
+            Edge::Arc(
+                point(max_x, max_y - radius),
+                point(max_x - inset, max_y - inset),
+                point(max_x - radius, max_y),
+            ),
+            Edge::Line(point(max_x - radius, max_y), point(min_x + radius, max_y)),
+            Edge::Arc(
+                point(min_x + radius, max_y),
+                point(min_x + inset, max_y - inset),
+                point(min_x, max_y - radius),
+            ),
+            Edge::Line(point(min_x, max_y - radius), point(min_x, min_y + radius)),
+            Edge::Arc(
+                point(min_x, min_y + radius),
+                point(min_x + inset, min_y + inset),
+                point(min_x + radius, min_y),
+            ),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Circle, length};
+
+    #[test]
+    fn from_dim_zero_radius() {
+        assert_eq!(
+            RoundedRectangle::from_dim(length!(1 m), length!(2 m), length!(0)),
+            Rectangle::from_dim(length!(1 m), length!(2 m))
+        )
+    }
+
+    #[test]
+    fn from_dim_full_radius() {
+        assert_eq!(
+            RoundedRectangle::from_dim(length!(2 m), length!(2 m), length!(1 m)),
+            Circle::from_radius(length!(1 m))
+        )
+    }
+
+    #[test]
+    fn from_dim_empty() {
+        assert_eq!(
+            RoundedRectangle::from_dim(length!(0), length!(2 m), length!(0)),
+            Sketch::empty()
+        );
+        assert_eq!(
+            RoundedRectangle::from_dim(length!(2 m), length!(2 m), length!(1.5 m)),
+            Sketch::empty()
+        );
+        assert_eq!(
+            RoundedRectangle::from_dim(length!(2 m), length!(2 m), length!(-1 m)),
+            Sketch::empty()
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{Circle, Edge, Length, Point2D, Sketch};
+
+/// Builder for an obround `Sketch`, i.e. a rectangle with semicircles on two opposing sides.
+///
+/// While the `Slot` struct itself is not used, its constructor methods like `Slot::from_dim()` can
+/// be used to build this primitive `Sketch`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Slot;
+impl Slot {
+    /// Construct a centered slot `Sketch` from its total length in x direction and its width in y
+    /// direction.
+    ///
+    /// If the length is smaller than the width, an empty `Sketch` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use core::f64;
+    /// use anvil::{length, Point2D, Slot};
+    ///
+    /// let slot = Slot::from_dim(length!(3 m), length!(1 m));
+    /// assert!((slot.area() - (2. + f64::consts::PI / 4.)).abs() < 1e-9);
+    /// assert_eq!(slot.center(), Ok(Point2D::origin()));
+    /// ```
+    pub fn from_dim(length: Length, width: Length) -> Sketch {
+        if width <= Length::zero() || length < width {
+            return Sketch::empty();
+        }
+        let radius = width / 2.;
+        let half_straight = (length - width) / 2.;
+        if half_straight == Length::zero() {
+            return Circle::from_radius(radius);
+        }
+
+        let zero = Length::zero();
+        Sketch::from_edges(vec![
+            Edge::Line(
+                Point2D::new(half_straight * -1., radius * -1.),
+                Point2D::new(half_straight, radius * -1.),
+            ),
+            Edge::Arc(
+                Point2D::new(half_straight, radius * -1.),
+                Point2D::new(half_straight + radius, zero),
+                Point2D::new(half_straight, radius),
+            ),
+            Edge::Line(
+                Point2D::new(half_straight, radius),
+                Point2D::new(half_straight * -1., radius),
+            ),
+            Edge::Arc(
+                Point2D::new(half_straight * -1., radius),
+                Point2D::new(half_straight * -1. - radius, zero),
+                Point2D::new(half_straight * -1., radius * -1.),
+            ),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::length;
+
+    #[test]
+    fn from_dim_equal_length_and_width() {
+        assert_eq!(
+            Slot::from_dim(length!(2 m), length!(2 m)),
+            Circle::from_radius(length!(1 m))
+        )
+    }
+
+    #[test]
+    fn from_dim_empty() {
+        assert_eq!(Slot::from_dim(length!(2 m), length!(0)), Sketch::empty());
+        assert_eq!(Slot::from_dim(length!(1 m), length!(2 m)), Sketch::empty());
+    }
+}
@@ -1,11 +1,17 @@
+use core::f64;
 use std::vec;
 
 use cxx::UniquePtr;
 use opencascade_sys::ffi;
 
-use crate::{angle, Angle, Axis, Error, Length, Part, Plane, Point2D, Point3D};
+use crate::{
+    angle,
+    parts::{mirror_occt, occt_bounding_box},
+    Angle, Axis, Error, Length, Part, Path3D, Plane, Point2D, Point3D, SweepOrientation, Vec2,
+    Vec3,
+};
 
-use super::Edge;
+use super::{Edge, JoinStyle, Path};
 
 /// A closed shape in 2D space.
 #[derive(Debug, Clone)]
@@ -69,6 +75,48 @@ impl Sketch {
         })
     }
 
+    /// Return the corners with the smallest and largest coordinates of the tightest axis-aligned
+    /// rectangle that encloses this `Sketch`.
+    ///
+    /// The rectangle is computed from the exact geometry of the `Sketch`. If a quick estimate is
+    /// sufficient, `Sketch::loose_bounding_box` is faster. If the `Sketch` is empty, an
+    /// `Err(Error::EmptySketch)` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Circle, length, Point2D};
+    ///
+    /// let circle = Circle::from_radius(length!(1 m)).move_to(Point2D::from_m(2., 0.));
+    /// let (min, max) = circle.bounding_box().unwrap();
+    /// assert!((min.x - length!(1 m)).m().abs() < 1e-6);
+    /// assert!((max.y - length!(1 m)).m().abs() < 1e-6);
+    /// ```
+    pub fn bounding_box(&self) -> Result<(Point2D, Point2D), Error> {
+        let (min, max) =
+            occt_bounding_box(&self.to_occt(&Plane::xy())?, true).ok_or(Error::EmptySketch)?;
+        Ok((Point2D::new(min.x, min.y), Point2D::new(max.x, max.y)))
+    }
+    /// Return the corners with the smallest and largest coordinates of an axis-aligned rectangle
+    /// that encloses this `Sketch`.
+    ///
+    /// The rectangle is computed quickly from the triangulation and tolerances of the `Sketch`,
+    /// which means that it may be slightly larger than `Sketch::bounding_box`. If the `Sketch` is
+    /// empty, an `Err(Error::EmptySketch)` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{length, Rectangle};
+    ///
+    /// let rect = Rectangle::from_dim(length!(2 m), length!(4 m));
+    /// let (min, max) = rect.loose_bounding_box().unwrap();
+    /// assert!(min.y <= length!(-2 m) && max.y >= length!(2 m));
+    /// ```
+    pub fn loose_bounding_box(&self) -> Result<(Point2D, Point2D), Error> {
+        let (min, max) =
+            occt_bounding_box(&self.to_occt(&Plane::xy())?, false).ok_or(Error::EmptySketch)?;
+        Ok((Point2D::new(min.x, min.y), Point2D::new(max.x, max.y)))
+    }
+
     /// Merge this `Sketch` with another.
     ///
     /// # Example
@@ -88,6 +136,46 @@ impl Sketch {
         Self(new_actions)
     }
 
+    /// Return a clone of this `Sketch` with all corners bevelled by a distance.
+    ///
+    /// The distance is measured from the corner along both adjacent edges. Smooth transitions
+    /// between edges are not corners and stay unchanged. If the chamfer can not be applied, e.g.
+    /// because the distance is longer than one of the edges, building a `Part` from the resulting
+    /// `Sketch` returns an `Err(Error::Chamfer)`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{length, Point2D, Rectangle};
+    ///
+    /// let sketch = Rectangle::from_corners(Point2D::origin(), Point2D::from_m(2., 2.));
+    /// let chamfered = sketch.chamfer(length!(0.5 m));
+    /// assert!((chamfered.area() - (4. - 4. * 0.125)).abs() < 1e-6)
+    /// ```
+    pub fn chamfer(&self, distance: Length) -> Self {
+        let mut new_actions = self.0.clone();
+        new_actions.push(SketchAction::Chamfer(distance, None));
+        Self(new_actions)
+    }
+
+    /// Return a clone of this `Sketch` with the corners at specific points bevelled by a
+    /// distance.
+    ///
+    /// Points that do not coincide with a corner of the `Sketch` are ignored.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{length, Point2D, Rectangle};
+    ///
+    /// let sketch = Rectangle::from_corners(Point2D::origin(), Point2D::from_m(2., 2.));
+    /// let chamfered = sketch.chamfer_corners(length!(0.5 m), &[Point2D::origin()]);
+    /// assert!((chamfered.area() - (4. - 0.125)).abs() < 1e-6)
+    /// ```
+    pub fn chamfer_corners(&self, distance: Length, corners: &[Point2D]) -> Self {
+        let mut new_actions = self.0.clone();
+        new_actions.push(SketchAction::Chamfer(distance, Some(corners.to_vec())));
+        Self(new_actions)
+    }
+
     /// Create multiple instances of the `Sketch` spaced evenly around a point.
     ///
     /// # Example
@@ -113,6 +201,78 @@ impl Sketch {
         }
         new_shape
     }
+    /// Return a clone of this `Sketch` with all corners rounded by a radius.
+    ///
+    /// Smooth transitions between edges are not corners and stay unchanged. If the fillet can not
+    /// be applied, e.g. because the radius is too large for the adjacent edges, building a `Part`
+    /// from the resulting `Sketch` returns an `Err(Error::Fillet)`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use core::f64;
+    /// use anvil::{length, Point2D, Rectangle};
+    ///
+    /// let sketch = Rectangle::from_corners(Point2D::origin(), Point2D::from_m(2., 2.));
+    /// let filleted = sketch.fillet(length!(0.5 m));
+    /// assert!((filleted.area() - (4. - 1. + f64::consts::PI / 4.)).abs() < 1e-6)
+    /// ```
+    pub fn fillet(&self, radius: Length) -> Self {
+        let mut new_actions = self.0.clone();
+        new_actions.push(SketchAction::Fillet(radius, None));
+        Self(new_actions)
+    }
+
+    /// Return a clone of this `Sketch` with the corners at specific points rounded by a radius.
+    ///
+    /// Points that do not coincide with a corner of the `Sketch` are ignored.
+    ///
+    /// # Example
+    /// ```rust
+    /// use core::f64;
+    /// use anvil::{length, Point2D, Rectangle};
+    ///
+    /// let sketch = Rectangle::from_corners(Point2D::origin(), Point2D::from_m(2., 2.));
+    /// let filleted = sketch.fillet_corners(length!(0.5 m), &[Point2D::from_m(2., 2.)]);
+    /// assert!((filleted.area() - (4. - 0.25 + f64::consts::PI / 16.)).abs() < 1e-6)
+    /// ```
+    pub fn fillet_corners(&self, radius: Length, corners: &[Point2D]) -> Self {
+        let mut new_actions = self.0.clone();
+        new_actions.push(SketchAction::Fillet(radius, Some(corners.to_vec())));
+        Self(new_actions)
+    }
+
+    /// Create a rectangular grid of instances of this `Sketch`.
+    ///
+    /// The instances are spaced along the x- and y-axis, starting from the current position of the
+    /// `Sketch`. A count of zero or one along an axis results in no repetition along it.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{length, point, Rectangle};
+    ///
+    /// let rect = Rectangle::from_dim(length!(1 m), length!(1 m));
+    /// assert_eq!(
+    ///     rect.grid_pattern(length!(2 m), length!(3 m), 2, 2),
+    ///     rect.add(&rect.move_to(point!(2 m, 0 m)))
+    ///         .add(&rect.move_to(point!(0 m, 3 m)))
+    ///         .add(&rect.move_to(point!(2 m, 3 m)))
+    /// )
+    /// ```
+    pub fn grid_pattern(
+        &self,
+        x_spacing: Length,
+        y_spacing: Length,
+        x_count: usize,
+        y_count: usize,
+    ) -> Self {
+        let row = (1..x_count).fold(self.clone(), |pattern, instance| {
+            pattern.add(&self.translate(Point2D::new(x_spacing * instance as f64, Length::zero())))
+        });
+        (1..y_count).fold(row.clone(), |pattern, instance| {
+            pattern.add(&row.translate(Point2D::new(Length::zero(), y_spacing * instance as f64)))
+        })
+    }
+
     /// Return the `Sketch` that is created from the overlapping area between this one and another.
     ///
     /// # Example
@@ -131,6 +291,74 @@ impl Sketch {
         new_actions.push(SketchAction::Intersect(other.clone()));
         Self(new_actions)
     }
+    /// Create multiple instances of this `Sketch` spaced evenly along a direction.
+    ///
+    /// The first instance stays at the current position of the `Sketch` and every following one is
+    /// moved by the spacing along the direction. If the direction has no length, an
+    /// `Err(Error::ZeroVector2D)` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{length, point, Rectangle, Vec2};
+    ///
+    /// let rect = Rectangle::from_dim(length!(1 m), length!(1 m));
+    /// assert_eq!(
+    ///     rect.linear_pattern(Vec2::from((0., 1.)), length!(2 m), 3),
+    ///     Ok(rect
+    ///         .add(&rect.move_to(point!(0 m, 2 m)))
+    ///         .add(&rect.move_to(point!(0 m, 4 m))))
+    /// )
+    /// ```
+    pub fn linear_pattern(
+        &self,
+        direction: Vec2,
+        spacing: Length,
+        count: usize,
+    ) -> Result<Self, Error> {
+        let direction = direction.normalize()?;
+        Ok((1..count).fold(self.clone(), |pattern, instance| {
+            pattern.add(&self.translate(direction * (spacing * instance as f64)))
+        }))
+    }
+    /// Return a mirror image of this `Sketch` across a line.
+    ///
+    /// The line passes through a point and runs along a direction. If the direction has no length,
+    /// an `Err(Error::ZeroVector2D)` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{point, Point2D, Rectangle, Vec2};
+    ///
+    /// let rect = Rectangle::from_corners(point!(1 m, 0 m), point!(3 m, 2 m));
+    /// assert_eq!(
+    ///     rect.mirror(Point2D::origin(), Vec2::from((0., 1.))),
+    ///     Ok(Rectangle::from_corners(point!(-3 m, 0 m), point!(-1 m, 2 m)))
+    /// )
+    /// ```
+    pub fn mirror(&self, point: Point2D, direction: Vec2) -> Result<Self, Error> {
+        let mut new_actions = self.0.clone();
+        new_actions.push(SketchAction::Mirror(point, direction.normalize()?));
+        Ok(Self(new_actions))
+    }
+    /// Return the `Sketch` that is created by merging this one with its mirror image across a
+    /// line.
+    ///
+    /// The line passes through a point and runs along a direction. If the direction has no length,
+    /// an `Err(Error::ZeroVector2D)` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{point, Point2D, Rectangle, Vec2};
+    ///
+    /// let rect = Rectangle::from_corners(point!(0 m, 0 m), point!(1 m, 2 m));
+    /// assert_eq!(
+    ///     rect.mirror_and_add(Point2D::origin(), Vec2::from((0., 1.))),
+    ///     Ok(Rectangle::from_corners(point!(-1 m, 0 m), point!(1 m, 2 m)))
+    /// )
+    /// ```
+    pub fn mirror_and_add(&self, point: Point2D, direction: Vec2) -> Result<Self, Error> {
+        Ok(self.add(&self.mirror(point, direction)?))
+    }
     /// Return a clone of this `Sketch` with the center moved to a specified point.
     ///
     /// # Example
@@ -147,6 +375,65 @@ impl Sketch {
         new_actions.push(SketchAction::MoveTo(loc));
         Self(new_actions)
     }
+    /// Return a clone of this `Sketch` with its outline moved outwards by a distance.
+    ///
+    /// Positive distances grow the `Sketch` and negative distances shrink it. The `JoinStyle`
+    /// determines the shape of the corners that are created where the offset edges no longer
+    /// meet. If the outline can not be offset, building the `Sketch` results in an
+    /// `Err(Error::Offset)`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{JoinStyle, length, Point2D, Rectangle};
+    ///
+    /// let sketch = Rectangle::from_corners(Point2D::origin(), Point2D::from_m(2., 2.));
+    /// assert_eq!(
+    ///     sketch.offset(length!(1 m), JoinStyle::Miter),
+    ///     Rectangle::from_corners(Point2D::from_m(-1., -1.), Point2D::from_m(3., 3.))
+    /// );
+    /// assert_eq!(
+    ///     sketch.offset(length!(-0.5 m), JoinStyle::Round),
+    ///     Rectangle::from_corners(Point2D::from_m(0.5, 0.5), Point2D::from_m(1.5, 1.5))
+    /// );
+    /// ```
+    pub fn offset(&self, distance: Length, join: JoinStyle) -> Self {
+        let mut new_actions = self.0.clone();
+        new_actions.push(SketchAction::Offset(distance, join));
+        Self(new_actions)
+    }
+    /// Create multiple instances of this `Sketch` spaced evenly along a `Path`.
+    ///
+    /// The first instance stays at the current position of the `Sketch`. Every following one is
+    /// moved by the offset between the start of the path and a point further along it. On an open
+    /// path, the last instance is placed at the end of the path; on a closed path, the instances
+    /// are spread around the whole loop. If the path is empty, an `Err(Error::EmptyPath)` is
+    /// returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{length, Path, point, Rectangle};
+    ///
+    /// let rect = Rectangle::from_dim(length!(1 m), length!(1 m));
+    /// let path = Path::at(point!(0 m, 0 m))
+    ///     .line_to(point!(2 m, 0 m))
+    ///     .line_to(point!(2 m, 2 m));
+    /// assert_eq!(
+    ///     rect.pattern_along(&path, 3),
+    ///     Ok(rect
+    ///         .add(&rect.move_to(point!(2 m, 0 m)))
+    ///         .add(&rect.move_to(point!(2 m, 2 m))))
+    /// )
+    /// ```
+    pub fn pattern_along(&self, path: &Path, count: usize) -> Result<Self, Error> {
+        let start = path.start();
+        Ok(path
+            .points_along(count)?
+            .into_iter()
+            .skip(1)
+            .fold(self.clone(), |pattern, point| {
+                pattern.add(&self.translate(point - start))
+            }))
+    }
     /// Return a clone of this `Sketch` rotated around its center.
     ///
     /// Positive angle values result in a counter-clockwise rotation.
@@ -250,14 +537,117 @@ impl Sketch {
         Ok(Part::from_occt(make_solid.pin_mut().Shape()))
     }
 
+    /// Convert this `Sketch` into a `Part` by revolving it around an `Axis`.
+    ///
+    /// For positive angles, the right-hand-rule applies for the direction of rotation. Angles
+    /// larger than a full rotation are treated as a full rotation. An angle of zero results in an
+    /// empty `Part`. If the revolution fails, an `Err(Error::Revolve)` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{angle, Axis, Cylinder, length, Plane, Point2D, Point3D, Rectangle};
+    ///
+    /// let sketch = Rectangle::from_corners(Point2D::origin(), Point2D::from_m(1., 2.));
+    /// assert_eq!(
+    ///     sketch.revolve(&Plane::xz(), Axis::z(), angle!(360 deg)),
+    ///     Ok(Cylinder::from_radius(length!(1 m), length!(2 m)).move_to(Point3D::from_m(0., 0., 1.)))
+    /// );
+    /// ```
+    pub fn revolve(&self, plane: &Plane, axis: Axis, angle: Angle) -> Result<Part, Error> {
+        let shape = self.to_occt(plane)?;
+        if angle == Angle::zero() {
+            return Ok(Part::empty());
+        }
+
+        let mut make_solid = ffi::BRepPrimAPI_MakeRevol_ctor(
+            &shape,
+            &axis.to_occt_ax1(),
+            angle.rad().clamp(-f64::consts::TAU, f64::consts::TAU),
+            false,
+        );
+        if !make_solid.IsDone() {
+            return Err(Error::Revolve(angle));
+        }
+
+        Ok(Part::from_occt(make_solid.pin_mut().Shape()))
+    }
+
+    /// Convert this `Sketch` into a `Part` by moving it along a `Path3D`.
+    ///
+    /// The `Sketch` is placed on the given `Plane`, which should usually cross the start of the
+    /// path. The `SweepOrientation` defines whether the profile keeps its orientation or rotates
+    /// with the path. Errors of the profile, of the path and an `Error::Sweep` for a profile that
+    /// can not be swept along the path are wrapped in an `Error::Context` naming the culprit.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Circle, Cylinder, length, Path3D, Plane, Point3D, SweepOrientation};
+    ///
+    /// let sketch = Circle::from_radius(length!(1 m));
+    /// let path = Path3D::at(Point3D::origin()).line_to(Point3D::from_m(0., 0., 2.));
+    /// assert_eq!(
+    ///     sketch.sweep(&Plane::xy(), &path, SweepOrientation::Fixed),
+    ///     Ok(Cylinder::from_radius(length!(1 m), length!(2 m)).move_to(Point3D::from_m(0., 0., 1.)))
+    /// );
+    /// ```
+    pub fn sweep(
+        &self,
+        plane: &Plane,
+        path: &Path3D,
+        orientation: SweepOrientation,
+    ) -> Result<Part, Error> {
+        let profile = self
+            .to_occt_wire(plane)
+            .map_err(|err| err.context("sweep", "profile"))?;
+        let spine = path.to_occt().map_err(|err| err.context("sweep", "path"))?;
+
+        let mut make_pipe = ffi::BRepOffsetAPI_MakePipeShell_ctor(&spine);
+        match orientation {
+            SweepOrientation::Fixed => ffi::BRepOffsetAPI_MakePipeShell_SetMode_fixed(
+                make_pipe.pin_mut(),
+                &plane.normal_axis().to_occt_ax2(),
+            ),
+            SweepOrientation::Frenet => make_pipe.pin_mut().SetMode(true),
+        }
+        make_pipe
+            .pin_mut()
+            .Add(ffi::cast_wire_to_shape(&profile), false, false);
+        make_pipe
+            .pin_mut()
+            .Build(&ffi::Message_ProgressRange_ctor());
+        if !make_pipe.IsDone() {
+            return Err(Error::Sweep.context("sweep", "profile"));
+        }
+        make_pipe.pin_mut().MakeSolid();
+
+        Ok(Part::from_occt(make_pipe.pin_mut().Shape()))
+    }
+
     pub(crate) fn from_edges(edges: Vec<Edge>) -> Self {
         Self(vec![SketchAction::AddEdges(edges)])
     }
 
+    /// Construct a `Sketch` that covers the area between the offsets of a series of edges to their
+    /// left and right.
+    pub(crate) fn from_stroke(edges: Vec<Edge>, left: Length, right: Length) -> Self {
+        Self(vec![SketchAction::AddStroke(edges, left, right)])
+    }
+
+    /// Return the outer boundary of the first face of this `Sketch`.
+    pub(crate) fn to_occt_wire(&self, plane: &Plane) -> Result<UniquePtr<ffi::TopoDS_Wire>, Error> {
+        let shape = self.to_occt(plane)?;
+        let explorer = ffi::TopExp_Explorer_ctor(&shape, ffi::TopAbs_ShapeEnum::TopAbs_FACE);
+        if !explorer.More() {
+            return Err(Error::EmptySketch);
+        }
+        let face = ffi::TopoDS_cast_to_face(explorer.Current());
+        Ok(ffi::outer_wire(face))
+    }
+
     pub(crate) fn to_occt(&self, plane: &Plane) -> Result<UniquePtr<ffi::TopoDS_Shape>, Error> {
         let mut occt = None;
         for action in &self.0 {
-            occt = action.apply(occt, plane);
+            occt = action.apply(occt, plane)?;
         }
 
         match occt {
@@ -265,6 +655,13 @@ impl Sketch {
             None => Err(Error::EmptySketch),
         }
     }
+
+    /// Return a clone of this `Sketch` moved by an offset.
+    fn translate(&self, offset: Point2D) -> Self {
+        let mut new_actions = self.0.clone();
+        new_actions.push(SketchAction::Translate(offset));
+        Self(new_actions)
+    }
 }
 
 impl PartialEq for Sketch {
@@ -284,6 +681,13 @@ impl PartialEq for Sketch {
 }
 
 fn edges_to_occt(edges: &[Edge], plane: &Plane) -> Result<UniquePtr<ffi::TopoDS_Shape>, Error> {
+    let wire = edges_to_occt_wire(edges, plane)?;
+    let make_face = ffi::BRepBuilderAPI_MakeFace_wire(&wire, false);
+    let face = make_face.Face();
+    Ok(ffi::TopoDS_Shape_to_owned(ffi::cast_face_to_shape(face)))
+}
+
+fn edges_to_occt_wire(edges: &[Edge], plane: &Plane) -> Result<UniquePtr<ffi::TopoDS_Wire>, Error> {
     let occt_edges: Vec<UniquePtr<ffi::TopoDS_Edge>> = edges
         .iter()
         .filter_map(|edge| edge.to_occt(plane))
@@ -297,11 +701,308 @@ fn edges_to_occt(edges: &[Edge], plane: &Plane) -> Result<UniquePtr<ffi::TopoDS_
     for edge in occt_edges {
         make_wire.pin_mut().add_edge(&edge)
     }
-    let wire = ffi::TopoDS_Wire_to_owned(make_wire.pin_mut().Wire());
+    Ok(ffi::TopoDS_Wire_to_owned(make_wire.pin_mut().Wire()))
+}
 
-    let make_face = ffi::BRepBuilderAPI_MakeFace_wire(&wire, false);
-    let face = make_face.Face();
-    Ok(ffi::TopoDS_Shape_to_owned(ffi::cast_face_to_shape(face)))
+/// Sweep a line perpendicular to the edges along them, with the line reaching a distance to the
+/// left and to the right of the edges.
+fn stroke_to_occt(
+    edges: &[Edge],
+    left: Length,
+    right: Length,
+    plane: &Plane,
+) -> Result<UniquePtr<ffi::TopoDS_Shape>, Error> {
+    let first_edge = edges.first().ok_or(Error::EmptyPath)?;
+    let (x, y) = first_edge
+        .start_direction()
+        .ok_or(Error::Thicken(left + right))?;
+    let start = first_edge.start();
+    let left_normal = Point2D::from_m(-y, x);
+    let profile_edge = Edge::Line(
+        start + left_normal * left.m(),
+        start - left_normal * right.m(),
+    )
+    .to_occt(plane)
+    .ok_or(Error::Thicken(left + right))?;
+
+    let mut make_profile = ffi::BRepBuilderAPI_MakeWire_ctor();
+    make_profile.pin_mut().add_edge(&profile_edge);
+    let profile = ffi::TopoDS_Wire_to_owned(make_profile.pin_mut().Wire());
+    let spine = edges_to_occt_wire(edges, plane)?;
+
+    let mut make_pipe = ffi::BRepOffsetAPI_MakePipeShell_ctor(&spine);
+    ffi::BRepOffsetAPI_MakePipeShell_SetMode_binormal(
+        make_pipe.pin_mut(),
+        &plane.normal().to_occt_dir(),
+    );
+    make_pipe
+        .pin_mut()
+        .SetTransitionMode(ffi::BRepBuilderAPI_TransitionMode::BRepBuilderAPI_RightCorner);
+    make_pipe
+        .pin_mut()
+        .Add(ffi::cast_wire_to_shape(&profile), false, false);
+    make_pipe
+        .pin_mut()
+        .Build(&ffi::Message_ProgressRange_ctor());
+    if !make_pipe.IsDone() {
+        return Err(Error::Thicken(left + right));
+    }
+
+    // merge the faces that were created for every single edge
+    let mut unify =
+        ffi::ShapeUpgrade_UnifySameDomain_ctor(make_pipe.pin_mut().Shape(), true, true, false);
+    unify.pin_mut().Build();
+    Ok(ffi::TopoDS_Shape_to_owned(unify.Shape()))
+}
+
+fn offset_occt(
+    shape: &ffi::TopoDS_Shape,
+    distance: Length,
+    join: JoinStyle,
+) -> Result<Option<UniquePtr<ffi::TopoDS_Shape>>, Error> {
+    if distance == Length::zero() {
+        return Ok(Some(ffi::TopoDS_Shape_to_owned(shape)));
+    }
+    let join_type = match join {
+        JoinStyle::Miter => ffi::GeomAbs_JoinType::GeomAbs_Intersection,
+        JoinStyle::Round => ffi::GeomAbs_JoinType::GeomAbs_Arc,
+    };
+
+    let mut result: Option<UniquePtr<ffi::TopoDS_Shape>> = None;
+    let mut face_explorer = ffi::TopExp_Explorer_ctor(shape, ffi::TopAbs_ShapeEnum::TopAbs_FACE);
+    while face_explorer.More() {
+        let face = ffi::TopoDS_cast_to_face(face_explorer.Current());
+        let mut make_offset = ffi::BRepOffsetAPI_MakeOffset_face_ctor(face, join_type);
+        make_offset.pin_mut().Perform(distance.m(), 0.);
+        face_explorer.pin_mut().Next();
+        if !make_offset.IsDone() {
+            return Err(Error::Offset(distance));
+        }
+
+        let mut wire_faces: Vec<UniquePtr<ffi::TopoDS_Shape>> = vec![];
+        let offset_shape = make_offset.pin_mut().Shape();
+        let mut wire_explorer =
+            ffi::TopExp_Explorer_ctor(offset_shape, ffi::TopAbs_ShapeEnum::TopAbs_WIRE);
+        while wire_explorer.More() {
+            let wire = ffi::TopoDS_cast_to_wire(wire_explorer.Current());
+            let make_face = ffi::BRepBuilderAPI_MakeFace_wire(wire, true);
+            let wire_face = ffi::TopoDS_Shape_to_owned(ffi::cast_face_to_shape(make_face.Face()));
+            wire_faces.push(wire_face);
+            wire_explorer.pin_mut().Next();
+        }
+
+        // the wires are not ordered, so the one enclosing the largest area is the outer boundary
+        // and all others are holes
+        let areas: Vec<f64> = wire_faces
+            .iter()
+            .map(|face| occt_area(face).abs())
+            .collect();
+        let outer_index = (0..wire_faces.len()).max_by(|&a, &b| areas[a].total_cmp(&areas[b]));
+        let offset_face = outer_index.map(|outer_index| {
+            let outer = wire_faces.remove(outer_index);
+            wire_faces.iter().fold(outer, |outer, hole| {
+                let mut operation = ffi::BRepAlgoAPI_Cut_ctor(&outer, hole);
+                ffi::TopoDS_Shape_to_owned(operation.pin_mut().Shape())
+            })
+        });
+
+        result = match (result, offset_face) {
+            (result, None) => result,
+            (None, Some(offset_face)) => Some(offset_face),
+            (Some(result), Some(offset_face)) => {
+                let mut operation = ffi::BRepAlgoAPI_Fuse_ctor(&result, &offset_face);
+                Some(ffi::TopoDS_Shape_to_owned(operation.pin_mut().Shape()))
+            }
+        };
+    }
+
+    Ok(match result {
+        Some(shape) if occt_area(&shape) > 0. => Some(shape),
+        _ => None,
+    })
+}
+
+/// The modification that is applied to the corners of a `Sketch`.
+enum Corner {
+    Chamfer(Length),
+    Fillet(Length),
+}
+
+/// Apply a fillet or chamfer to the corners of every face of a shape.
+///
+/// If `corners` is `None`, all corners are modified. Otherwise only the corners located at one of
+/// the given points are.
+///
+/// If the modification can not be applied to one of the corners, an `Err(Error::Chamfer)` or
+/// `Err(Error::Fillet)` is returned.
+fn modify_corners(
+    shape: &ffi::TopoDS_Shape,
+    corner: Corner,
+    corners: Option<&[Point2D]>,
+    plane: &Plane,
+) -> Result<UniquePtr<ffi::TopoDS_Shape>, Error> {
+    let selected_points: Option<Vec<Point3D>> =
+        corners.map(|points| points.iter().map(|point| point.to_3d(plane)).collect());
+
+    // merge edges that were split by boolean operations so that only real corners remain
+    let mut unify = ffi::ShapeUpgrade_UnifySameDomain_ctor(shape, true, true, false);
+    unify.pin_mut().Build();
+    let unified = ffi::TopoDS_Shape_to_owned(unify.Shape());
+
+    let mut result: Option<UniquePtr<ffi::TopoDS_Shape>> = None;
+    let mut face_explorer = ffi::TopExp_Explorer_ctor(&unified, ffi::TopAbs_ShapeEnum::TopAbs_FACE);
+    while face_explorer.More() {
+        let face = ffi::TopoDS_cast_to_face(face_explorer.Current());
+        let mut make_corners = ffi::BRepFilletAPI_MakeFillet2d_ctor(face);
+        let edges = unique_edges(ffi::cast_face_to_shape(face));
+        let mut n_corners = 0;
+
+        for vertex in unique_vertices(ffi::cast_face_to_shape(face)) {
+            let point = ffi::BRep_Tool_Pnt(&vertex);
+            let point = Point3D::from_m(point.X(), point.Y(), point.Z());
+            let is_selected = match &selected_points {
+                Some(selected_points) => selected_points
+                    .iter()
+                    .any(|selected| (*selected - point).distance_to_origin().m() < 1e-7),
+                None => true,
+            };
+            if !is_selected {
+                continue;
+            }
+
+            // only vertices between two edges that meet at an angle are corners, which excludes
+            // the seams of closed curves and the smooth transitions between lines and arcs
+            let adjacent: Vec<&UniquePtr<ffi::TopoDS_Edge>> = edges
+                .iter()
+                .filter(|edge| {
+                    [ffi::TopExp_FirstVertex(edge), ffi::TopExp_LastVertex(edge)]
+                        .iter()
+                        .any(|end| {
+                            ffi::cast_vertex_to_shape(end)
+                                .IsSame(ffi::cast_vertex_to_shape(&vertex))
+                        })
+                })
+                .collect();
+            let (first, second) = match adjacent[..] {
+                [first, second] => (first, second),
+                _ => continue,
+            };
+            if are_tangent(first, second, &vertex) {
+                continue;
+            }
+
+            match corner {
+                Corner::Chamfer(distance) => {
+                    ffi::BRepFilletAPI_MakeFillet2d_add_chamfer(
+                        make_corners.pin_mut(),
+                        first,
+                        second,
+                        distance.m(),
+                        distance.m(),
+                    );
+                }
+                Corner::Fillet(radius) => {
+                    ffi::BRepFilletAPI_MakeFillet2d_add_fillet(
+                        make_corners.pin_mut(),
+                        &vertex,
+                        radius.m(),
+                    );
+                }
+            }
+            n_corners += 1;
+        }
+
+        let modified_face = if n_corners == 0 {
+            ffi::TopoDS_Shape_to_owned(ffi::cast_face_to_shape(face))
+        } else {
+            make_corners
+                .pin_mut()
+                .Build(&ffi::Message_ProgressRange_ctor());
+            if !make_corners.IsDone() {
+                return Err(match corner {
+                    Corner::Chamfer(distance) => Error::Chamfer(distance),
+                    Corner::Fillet(radius) => Error::Fillet(radius),
+                });
+            }
+            ffi::TopoDS_Shape_to_owned(make_corners.pin_mut().Shape())
+        };
+
+        result = Some(match result {
+            None => modified_face,
+            Some(result) => {
+                let mut operation = ffi::BRepAlgoAPI_Fuse_ctor(&result, &modified_face);
+                ffi::TopoDS_Shape_to_owned(operation.pin_mut().Shape())
+            }
+        });
+        face_explorer.pin_mut().Next();
+    }
+    result.ok_or(Error::EmptySketch)
+}
+
+/// Return true if two edges that share a vertex continue in the same or opposite direction there.
+fn are_tangent(
+    first: &ffi::TopoDS_Edge,
+    second: &ffi::TopoDS_Edge,
+    vertex: &ffi::TopoDS_Vertex,
+) -> bool {
+    match (tangent_at(first, vertex), tangent_at(second, vertex)) {
+        (Some(first), Some(second)) => first.cross(second).magnitude() < 1e-4,
+        _ => false,
+    }
+}
+
+/// Return the direction of an edge at the end that touches a vertex.
+fn tangent_at(edge: &ffi::TopoDS_Edge, vertex: &ffi::TopoDS_Vertex) -> Option<Vec3> {
+    let curve = ffi::BRepAdaptor_Curve_ctor(edge);
+    let first = curve.FirstParameter();
+    let last = curve.LastParameter();
+    let step = (last - first) * 1e-6;
+    let starts_at_vertex = ffi::cast_vertex_to_shape(&ffi::TopExp_FirstVertex(edge))
+        .IsSame(ffi::cast_vertex_to_shape(vertex));
+    let (from, to) = if starts_at_vertex {
+        (first, first + step)
+    } else {
+        (last - step, last)
+    };
+
+    let from = ffi::BRepAdaptor_Curve_value(&curve, from);
+    let to = ffi::BRepAdaptor_Curve_value(&curve, to);
+    Vec3::from((to.X() - from.X(), to.Y() - from.Y(), to.Z() - from.Z()))
+        .normalize()
+        .ok()
+}
+
+/// Return the edges of an OpenCascade shape, with each edge only occurring once.
+pub(crate) fn unique_edges(shape: &ffi::TopoDS_Shape) -> Vec<UniquePtr<ffi::TopoDS_Edge>> {
+    let mut edges: Vec<UniquePtr<ffi::TopoDS_Edge>> = vec![];
+    let mut explorer = ffi::TopExp_Explorer_ctor(shape, ffi::TopAbs_ShapeEnum::TopAbs_EDGE);
+    while explorer.More() {
+        let edge = ffi::TopoDS_cast_to_edge(explorer.Current());
+        let is_duplicate = edges
+            .iter()
+            .any(|other| ffi::cast_edge_to_shape(other).IsSame(ffi::cast_edge_to_shape(edge)));
+        if !is_duplicate {
+            edges.push(ffi::TopoDS_Edge_to_owned(edge));
+        }
+        explorer.pin_mut().Next();
+    }
+    edges
+}
+
+fn unique_vertices(shape: &ffi::TopoDS_Shape) -> Vec<UniquePtr<ffi::TopoDS_Vertex>> {
+    let mut vertices: Vec<UniquePtr<ffi::TopoDS_Vertex>> = vec![];
+    let mut explorer = ffi::TopExp_Explorer_ctor(shape, ffi::TopAbs_ShapeEnum::TopAbs_VERTEX);
+    while explorer.More() {
+        let vertex = ffi::TopoDS_cast_to_vertex(explorer.Current());
+        let is_duplicate = vertices.iter().any(|other| {
+            ffi::cast_vertex_to_shape(other).IsSame(ffi::cast_vertex_to_shape(vertex))
+        });
+        if !is_duplicate {
+            vertices.push(ffi::TopoDS_Vertex_to_owned(vertex));
+        }
+        explorer.pin_mut().Next();
+    }
+    vertices
 }
 
 fn occt_area(occt: &ffi::TopoDS_Shape) -> f64 {
@@ -322,20 +1023,26 @@ fn occt_center(occt: &ffi::TopoDS_Shape) -> Point3D {
 enum SketchAction {
     Add(Sketch),
     AddEdges(Vec<Edge>),
+    AddStroke(Vec<Edge>, Length, Length),
+    Chamfer(Length, Option<Vec<Point2D>>),
+    Fillet(Length, Option<Vec<Point2D>>),
     Intersect(Sketch),
+    Mirror(Point2D, Vec2),
     MoveTo(Point2D),
+    Offset(Length, JoinStyle),
     RotateAround(Point2D, Angle),
     Scale(f64),
     Subtract(Sketch),
+    Translate(Point2D),
 }
 impl SketchAction {
     pub fn apply(
         &self,
         sketch: Option<UniquePtr<ffi::TopoDS_Shape>>,
         plane: &Plane,
-    ) -> Option<UniquePtr<ffi::TopoDS_Shape>> {
-        match self {
-            SketchAction::Add(other) => match (sketch, other.to_occt(plane).ok()) {
+    ) -> Result<Option<UniquePtr<ffi::TopoDS_Shape>>, Error> {
+        Ok(match self {
+            SketchAction::Add(other) => match (sketch, optional_occt(other, plane)?) {
                 (None, None) => None,
                 (None, Some(other)) => Some(other),
                 (Some(sketch), None) => Some(sketch),
@@ -344,11 +1051,44 @@ impl SketchAction {
                     Some(ffi::TopoDS_Shape_to_owned(operation.pin_mut().Shape()))
                 }
             },
-            SketchAction::AddEdges(edges) => match sketch {
-                None => edges_to_occt(edges, plane).ok(),
-                Some(_) => todo!(),
+            SketchAction::AddEdges(edges) => match (sketch, edges_to_occt(edges, plane).ok()) {
+                (None, None) => None,
+                (None, Some(face)) => Some(face),
+                (Some(sketch), None) => Some(sketch),
+                (Some(self_shape), Some(face)) => {
+                    let mut operation = ffi::BRepAlgoAPI_Fuse_ctor(&self_shape, &face);
+                    Some(ffi::TopoDS_Shape_to_owned(operation.pin_mut().Shape()))
+                }
+            },
+            SketchAction::AddStroke(edges, left, right) => {
+                let stroke = stroke_to_occt(edges, *left, *right, plane)?;
+                match sketch {
+                    None => Some(stroke),
+                    Some(self_shape) => {
+                        let mut operation = ffi::BRepAlgoAPI_Fuse_ctor(&self_shape, &stroke);
+                        Some(ffi::TopoDS_Shape_to_owned(operation.pin_mut().Shape()))
+                    }
+                }
+            }
+            SketchAction::Chamfer(distance, corners) => match sketch {
+                Some(shape) => Some(modify_corners(
+                    &shape,
+                    Corner::Chamfer(*distance),
+                    corners.as_deref(),
+                    plane,
+                )?),
+                None => None,
+            },
+            SketchAction::Fillet(radius, corners) => match sketch {
+                Some(shape) => Some(modify_corners(
+                    &shape,
+                    Corner::Fillet(*radius),
+                    corners.as_deref(),
+                    plane,
+                )?),
+                None => None,
             },
-            SketchAction::Intersect(other) => match (sketch, other.to_occt(plane).ok()) {
+            SketchAction::Intersect(other) => match (sketch, optional_occt(other, plane)?) {
                 (Some(self_shape), Some(other_shape)) => {
                     let mut operation = ffi::BRepAlgoAPI_Common_ctor(&self_shape, &other_shape);
                     let new_shape = ffi::TopoDS_Shape_to_owned(operation.pin_mut().Shape());
@@ -360,6 +1100,13 @@ impl SketchAction {
                 }
                 _ => None,
             },
+            SketchAction::Mirror(point, direction) => match sketch {
+                Some(shape) => {
+                    let normal = plane.x_axis() * -direction.y + plane.y_axis() * direction.x;
+                    Some(mirror_occt(&shape, point.to_3d(plane), normal))
+                }
+                None => None,
+            },
             SketchAction::MoveTo(loc) => match sketch {
                 Some(shape) => {
                     let mut transform = ffi::new_transform();
@@ -375,6 +1122,10 @@ impl SketchAction {
                 }
                 None => None,
             },
+            SketchAction::Offset(distance, join) => match sketch {
+                Some(shape) => offset_occt(&shape, *distance, *join)?,
+                None => None,
+            },
             SketchAction::RotateAround(point, angle) => match sketch {
                 Some(shape) => {
                     let mut transform = ffi::new_transform();
@@ -406,7 +1157,7 @@ impl SketchAction {
                 }
                 None => None,
             },
-            SketchAction::Subtract(other) => match (sketch, other.to_occt(plane).ok()) {
+            SketchAction::Subtract(other) => match (sketch, optional_occt(other, plane)?) {
                 (None, None) => None,
                 (None, Some(_)) => None,
                 (Some(sketch), None) => Some(sketch),
@@ -415,7 +1166,32 @@ impl SketchAction {
                     Some(ffi::TopoDS_Shape_to_owned(operation.pin_mut().Shape()))
                 }
             },
-        }
+            SketchAction::Translate(offset) => match sketch {
+                Some(shape) => {
+                    let move_vec = offset.to_3d(plane) - plane.origin();
+                    let mut transform = ffi::new_transform();
+                    transform
+                        .pin_mut()
+                        .set_translation_vec(&move_vec.to_occt_vec());
+                    let mut operation =
+                        ffi::BRepBuilderAPI_Transform_ctor(&shape, &transform, false);
+                    Some(ffi::TopoDS_Shape_to_owned(operation.pin_mut().Shape()))
+                }
+                None => None,
+            },
+        })
+    }
+}
+
+/// Convert a `Sketch` into an OpenCascade shape, returning `None` if it is empty.
+fn optional_occt(
+    sketch: &Sketch,
+    plane: &Plane,
+) -> Result<Option<UniquePtr<ffi::TopoDS_Shape>>, Error> {
+    match sketch.to_occt(plane) {
+        Ok(shape) => Ok(Some(shape)),
+        Err(Error::EmptySketch) => Ok(None),
+        Err(err) => Err(err),
     }
 }
 
@@ -423,11 +1199,42 @@ impl SketchAction {
 mod tests {
     use crate::{
         angle, length, sketches::primitives::Circle, Cuboid, Cylinder, Path, Point2D, Point3D,
-        Rectangle,
+        Rectangle, Slot,
     };
 
     use super::*;
 
+    #[test]
+    fn bounding_box_of_rotated_rectangle() {
+        let rect = Rectangle::from_dim(length!(2 m), length!(2 m))
+            .rotate(angle!(45 deg))
+            .move_to(Point2D::from_m(1., 1.));
+        let (min, max) = rect.bounding_box().unwrap();
+        let half_diagonal = 2_f64.sqrt();
+        assert!((min.x.m() - (1. - half_diagonal)).abs() < 1e-6);
+        assert!((min.y.m() - (1. - half_diagonal)).abs() < 1e-6);
+        assert!((max.x.m() - (1. + half_diagonal)).abs() < 1e-6);
+        assert!((max.y.m() - (1. + half_diagonal)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn loose_bounding_box_contains_bounding_box() {
+        let circle = Circle::from_radius(length!(1 m)).move_to(Point2D::from_m(-3., 2.));
+        let (tight_min, tight_max) = circle.bounding_box().unwrap();
+        let (loose_min, loose_max) = circle.loose_bounding_box().unwrap();
+        assert!(loose_min.x <= tight_min.x && loose_max.x >= tight_max.x);
+        assert!(loose_min.y <= tight_min.y && loose_max.y >= tight_max.y);
+    }
+
+    #[test]
+    fn bounding_box_of_empty_sketch() {
+        assert_eq!(Sketch::empty().bounding_box(), Err(Error::EmptySketch));
+        assert_eq!(
+            Sketch::empty().loose_bounding_box(),
+            Err(Error::EmptySketch)
+        );
+    }
+
     #[test]
     fn eq_both_rectangles() {
         assert_eq!(
@@ -484,6 +1291,147 @@ mod tests {
         )
     }
 
+    #[test]
+    fn add_edges_to_non_empty_sketch() {
+        let square = Rectangle::from_corners(Point2D::origin(), Point2D::from_m(1., 1.));
+        let triangle = Path::at(Point2D::from_m(1., 0.))
+            .line_to(Point2D::from_m(2., 0.))
+            .line_to(Point2D::from_m(1., 1.))
+            .close();
+        let sketch = Sketch(vec![
+            SketchAction::Add(square),
+            SketchAction::AddEdges(vec![
+                Edge::Line(Point2D::from_m(1., 0.), Point2D::from_m(2., 0.)),
+                Edge::Line(Point2D::from_m(2., 0.), Point2D::from_m(1., 1.)),
+                Edge::Line(Point2D::from_m(1., 1.), Point2D::from_m(1., 0.)),
+            ]),
+        ]);
+        assert_eq!(
+            sketch,
+            Rectangle::from_corners(Point2D::origin(), Point2D::from_m(1., 1.)).add(&triangle)
+        )
+    }
+
+    #[test]
+    fn offset_round_corners() {
+        let sketch = Rectangle::from_corners(Point2D::origin(), Point2D::from_m(2., 2.))
+            .offset(length!(1 m), JoinStyle::Round);
+        assert!((sketch.area() - (16. - 4. + f64::consts::PI)).abs() < 1e-6)
+    }
+
+    #[test]
+    fn offset_shrink_to_nothing() {
+        let sketch = Rectangle::from_corners(Point2D::origin(), Point2D::from_m(2., 2.))
+            .offset(length!(-2 m), JoinStyle::Miter);
+        assert_eq!(sketch.area(), 0.)
+    }
+
+    #[test]
+    fn offset_grow_sketch_with_hole() {
+        let sketch = Rectangle::from_dim(length!(4 m), length!(4 m))
+            .subtract(&Rectangle::from_dim(length!(2 m), length!(2 m)))
+            .offset(length!(0.5 m), JoinStyle::Miter);
+        assert!((sketch.area() - (25. - 1.)).abs() < 1e-6)
+    }
+
+    #[test]
+    fn offset_shrink_sketch_with_hole() {
+        let sketch = Rectangle::from_dim(length!(4 m), length!(4 m))
+            .subtract(&Rectangle::from_dim(length!(2 m), length!(2 m)))
+            .offset(length!(-0.25 m), JoinStyle::Miter);
+        assert!((sketch.area() - (12.25 - 6.25)).abs() < 1e-6)
+    }
+
+    #[test]
+    fn offset_composes_with_move_to() {
+        let sketch = Circle::from_radius(length!(1 m));
+        assert_eq!(
+            sketch
+                .offset(length!(1 m), JoinStyle::Round)
+                .move_to(Point2D::from_m(3., 0.)),
+            Circle::from_radius(length!(2 m)).move_to(Point2D::from_m(3., 0.))
+        )
+    }
+
+    #[test]
+    fn fillet_rectangle_equals_rounded_shape() {
+        let sketch = Rectangle::from_corners(Point2D::origin(), Point2D::from_m(2., 2.))
+            .fillet(length!(0.5 m));
+        let corner = Circle::from_radius(length!(0.5 m));
+        let expected = Rectangle::from_corners(Point2D::from_m(0.5, 0.), Point2D::from_m(1.5, 2.))
+            .add(&Rectangle::from_corners(
+                Point2D::from_m(0., 0.5),
+                Point2D::from_m(2., 1.5),
+            ))
+            .add(&corner.move_to(Point2D::from_m(0.5, 0.5)))
+            .add(&corner.move_to(Point2D::from_m(1.5, 0.5)))
+            .add(&corner.move_to(Point2D::from_m(0.5, 1.5)))
+            .add(&corner.move_to(Point2D::from_m(1.5, 1.5)));
+        assert_eq!(sketch, expected)
+    }
+
+    #[test]
+    fn fillet_radius_too_large() {
+        let sketch = Rectangle::from_corners(Point2D::origin(), Point2D::from_m(2., 2.))
+            .fillet(length!(2 m));
+        assert_eq!(
+            sketch.extrude(&Plane::xy(), length!(1 m)),
+            Err(Error::Fillet(length!(2 m)))
+        )
+    }
+
+    #[test]
+    fn fillet_slot_without_corners() {
+        let sketch = Slot::from_dim(length!(3 m), length!(1 m)).fillet(length!(0.1 m));
+        assert_eq!(sketch, Slot::from_dim(length!(3 m), length!(1 m)))
+    }
+
+    #[test]
+    fn fillet_only_corners_of_rounded_shape() {
+        let sketch = Rectangle::from_dim(length!(2 m), length!(2 m))
+            .add(&Circle::from_radius(length!(1 m)).move_to(Point2D::from_m(1., 0.)))
+            .fillet(length!(0.1 m));
+        let unfilleted_area = 4. + f64::consts::PI / 2.;
+        let removed_at_corner = 0.01 - f64::consts::PI * 0.01 / 4.;
+        assert!((sketch.area() - (unfilleted_area - 2. * removed_at_corner)).abs() < 1e-6)
+    }
+
+    #[test]
+    fn chamfer_circle_without_corners() {
+        let sketch = Circle::from_radius(length!(1 m)).chamfer(length!(0.1 m));
+        assert_eq!(sketch, Circle::from_radius(length!(1 m)))
+    }
+
+    #[test]
+    fn fillet_circle_without_corners() {
+        let sketch = Circle::from_radius(length!(1 m)).fillet(length!(0.1 m));
+        assert_eq!(sketch, Circle::from_radius(length!(1 m)))
+    }
+
+    #[test]
+    fn fillet_corner_of_union() {
+        let sketch = Rectangle::from_corners(Point2D::origin(), Point2D::from_m(1., 2.))
+            .add(&Rectangle::from_corners(
+                Point2D::from_m(1., 0.),
+                Point2D::from_m(2., 2.),
+            ))
+            .fillet_corners(length!(0.5 m), &[Point2D::from_m(2., 2.)]);
+        assert!((sketch.area() - (4. - 0.25 + f64::consts::PI / 16.)).abs() < 1e-6)
+    }
+
+    #[test]
+    fn chamfer_concave_corner() {
+        let sketch = Path::at(Point2D::origin())
+            .line_to(Point2D::from_m(2., 0.))
+            .line_to(Point2D::from_m(2., 1.))
+            .line_to(Point2D::from_m(1., 1.))
+            .line_to(Point2D::from_m(1., 2.))
+            .line_to(Point2D::from_m(0., 2.))
+            .close()
+            .chamfer_corners(length!(0.5 m), &[Point2D::from_m(1., 1.)]);
+        assert!((sketch.area() - (3. + 0.125)).abs() < 1e-6)
+    }
+
     #[test]
     fn intersect_non_overlapping() {
         let sketch1 = Rectangle::from_corners(Point2D::from_m(1., 1.), Point2D::from_m(2., 2.));
@@ -534,4 +1482,152 @@ mod tests {
                 .move_to(Point3D::from_m(0., 0., 1.)))
         )
     }
+
+    #[test]
+    fn sweep_empty_sketch() {
+        let path = Path3D::at(Point3D::origin()).line_to(Point3D::from_m(0., 0., 1.));
+        assert_eq!(
+            Sketch::empty().sweep(&Plane::xy(), &path, SweepOrientation::Fixed),
+            Err(Error::EmptySketch.context("sweep", "profile"))
+        )
+    }
+
+    #[test]
+    fn sweep_empty_path() {
+        let sketch = Circle::from_radius(length!(1 m));
+        assert_eq!(
+            sketch.sweep(
+                &Plane::xy(),
+                &Path3D::at(Point3D::origin()),
+                SweepOrientation::Fixed
+            ),
+            Err(Error::EmptyPath.context("sweep", "path"))
+        )
+    }
+
+    #[test]
+    fn sweep_along_arc() {
+        let sketch = Circle::from_radius(length!(0.1 m));
+        let plane = Plane::new(Point3D::from_m(1., 0., 0.), (0., 0., 1.), (1., 0., 0.)).unwrap();
+        let path = Path3D::at(Point3D::from_m(1., 0., 0.))
+            .arc_through(
+                Point3D::from_m(f64::sqrt(0.5), f64::sqrt(0.5), 0.),
+                Point3D::from_m(0., 1., 0.),
+            )
+            .unwrap();
+        let part = sketch
+            .sweep(&plane, &path, SweepOrientation::Frenet)
+            .unwrap();
+
+        let expected_volume = f64::consts::PI * 0.1_f64.powi(2) * f64::consts::FRAC_PI_2;
+        assert!((part.volume() - expected_volume).abs() < 1e-6)
+    }
+
+    #[test]
+    fn revolve_empty_sketch() {
+        assert_eq!(
+            Sketch::empty().revolve(&Plane::xz(), Axis::z(), angle!(360 deg)),
+            Err(Error::EmptySketch)
+        )
+    }
+
+    #[test]
+    fn revolve_zero_angle() {
+        let sketch = Rectangle::from_corners(Point2D::origin(), Point2D::from_m(1., 2.));
+        assert_eq!(
+            sketch.revolve(&Plane::xz(), Axis::z(), Angle::zero()),
+            Ok(Part::empty())
+        )
+    }
+
+    #[test]
+    fn revolve_half_cylinder() {
+        let sketch = Rectangle::from_corners(Point2D::origin(), Point2D::from_m(1., 2.));
+        let cylinder =
+            Cylinder::from_radius(length!(1 m), length!(2 m)).move_to(Point3D::from_m(0., 0., 1.));
+        assert_eq!(
+            sketch.revolve(&Plane::xz(), Axis::z(), angle!(180 deg)),
+            Ok(cylinder.intersect(&Cuboid::from_corners(
+                Point3D::from_m(-1., 0., 0.),
+                Point3D::from_m(1., 1., 2.)
+            )))
+        )
+    }
+
+    #[test]
+    fn revolve_more_than_full_rotation() {
+        let sketch = Rectangle::from_corners(Point2D::origin(), Point2D::from_m(1., 2.));
+        assert_eq!(
+            sketch.revolve(&Plane::xz(), Axis::z(), angle!(720 deg)),
+            sketch.revolve(&Plane::xz(), Axis::z(), angle!(360 deg))
+        )
+    }
+
+    #[test]
+    fn grid_pattern_area() {
+        let rect = Rectangle::from_dim(length!(1 m), length!(1 m));
+        let grid = rect.grid_pattern(length!(2 m), length!(2 m), 3, 4);
+        assert!((grid.area() - 12.).abs() < 1e-9)
+    }
+
+    #[test]
+    fn linear_pattern_on_rotated_plane() {
+        let rect = Rectangle::from_dim(length!(1 m), length!(1 m));
+        assert_eq!(
+            rect.linear_pattern(Vec2::from((1., 0.)), length!(2 m), 2)
+                .unwrap()
+                .extrude(&Plane::yz(), length!(1 m)),
+            rect.add(&rect.move_to(Point2D::from_m(2., 0.)))
+                .extrude(&Plane::yz(), length!(1 m))
+        )
+    }
+
+    #[test]
+    fn pattern_along_closed_path() {
+        let rect = Rectangle::from_dim(length!(1 m), length!(1 m));
+        let path = Path::at(Point2D::origin())
+            .line_to(Point2D::from_m(4., 0.))
+            .line_to(Point2D::from_m(4., 4.))
+            .line_to(Point2D::from_m(0., 4.))
+            .line_to(Point2D::origin());
+        assert_eq!(
+            rect.pattern_along(&path, 4),
+            Ok(rect
+                .add(&rect.move_to(Point2D::from_m(4., 0.)))
+                .add(&rect.move_to(Point2D::from_m(4., 4.)))
+                .add(&rect.move_to(Point2D::from_m(0., 4.))))
+        )
+    }
+
+    #[test]
+    fn mirror_across_offset_line() {
+        let rect = Rectangle::from_corners(Point2D::from_m(0., 0.), Point2D::from_m(2., 2.));
+        assert_eq!(
+            rect.mirror(Point2D::from_m(0., 3.), Vec2::from((2., 0.))),
+            Ok(Rectangle::from_corners(
+                Point2D::from_m(0., 4.),
+                Point2D::from_m(2., 6.)
+            ))
+        )
+    }
+
+    #[test]
+    fn mirror_extrudes_to_positive_volume() {
+        let rect = Rectangle::from_corners(Point2D::from_m(1., 0.), Point2D::from_m(3., 2.));
+        let mirrored = rect
+            .mirror(Point2D::origin(), Vec2::from((1., 1.)))
+            .unwrap()
+            .extrude(&Plane::xy(), length!(1 m))
+            .unwrap();
+        assert!((mirrored.volume() - 4.).abs() < 1e-9)
+    }
+
+    #[test]
+    fn mirror_zero_direction() {
+        let rect = Rectangle::from_dim(length!(1 m), length!(1 m));
+        assert_eq!(
+            rect.mirror(Point2D::origin(), Vec2::from((0., 0.))),
+            Err(Error::ZeroVector2D(Vec2::from((0., 0.))))
+        )
+    }
 }
@@ -0,0 +1,215 @@
+use std::fmt::Debug;
+
+use cxx::UniquePtr;
+use opencascade_sys::ffi;
+
+use crate::{Length, Point3D};
+
+/// The geometric type of the curve underlying an `Edge3D`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum CurveType {
+    /// A Bézier curve.
+    Bezier,
+    /// A B-spline curve.
+    BSpline,
+    /// A circle or circular arc.
+    Circle,
+    /// An ellipse or elliptical arc.
+    Ellipse,
+    /// A hyperbolic arc.
+    Hyperbola,
+    /// A straight line.
+    Line,
+    /// Any curve that is not covered by the other variants.
+    Other,
+    /// A parabolic arc.
+    Parabola,
+}
+
+/// A bounded curve on the boundary of a `Part`.
+///
+/// Edges are obtained from an existing `Part` through `Part::edges`.
+pub struct Edge3D {
+    pub(crate) inner: UniquePtr<ffi::TopoDS_Edge>,
+}
+impl Edge3D {
+    /// Return the starting point of this `Edge3D`.
+    ///
+    /// A reversed `Edge3D` starts at the end of its underlying curve.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, point};
+    ///
+    /// let cuboid = Cuboid::from_corners(point!(0 m, 0 m, 0 m), point!(1 m, 1 m, 1 m));
+    /// assert!(cuboid
+    ///     .edges()
+    ///     .iter()
+    ///     .all(|edge| {
+    ///         let distance = (edge.start() - edge.end()).distance_to_origin();
+    ///         (distance - edge.len()).m().abs() < 1e-9
+    ///     }));
+    /// ```
+    pub fn start(&self) -> Point3D {
+        let curve = ffi::BRepAdaptor_Curve_ctor(&self.inner);
+        if self.is_reversed() {
+            point_on(&curve, curve.LastParameter())
+        } else {
+            point_on(&curve, curve.FirstParameter())
+        }
+    }
+
+    /// Return the ending point of this `Edge3D`.
+    ///
+    /// A reversed `Edge3D` ends at the start of its underlying curve.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, point};
+    ///
+    /// let cuboid = Cuboid::from_corners(point!(0 m, 0 m, 0 m), point!(1 m, 1 m, 1 m));
+    /// assert!(cuboid.edges().iter().all(|edge| edge.end() != edge.start()));
+    /// ```
+    pub fn end(&self) -> Point3D {
+        let curve = ffi::BRepAdaptor_Curve_ctor(&self.inner);
+        if self.is_reversed() {
+            point_on(&curve, curve.FirstParameter())
+        } else {
+            point_on(&curve, curve.LastParameter())
+        }
+    }
+
+    /// Return the length of this `Edge3D`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, length};
+    ///
+    /// let cuboid = Cuboid::from_dim(length!(1 m), length!(2 m), length!(3 m));
+    /// let total_length = cuboid
+    ///     .edges()
+    ///     .iter()
+    ///     .fold(length!(0), |total, edge| total + edge.len());
+    /// assert!((total_length.m() - 24.).abs() < 1e-9);
+    /// ```
+    pub fn len(&self) -> Length {
+        Length::from_m(self.linear_properties().Mass())
+    }
+
+    /// Return the center of mass of this `Edge3D`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, point};
+    ///
+    /// let cuboid = Cuboid::from_corners(point!(0 m, 0 m, 0 m), point!(2 m, 2 m, 2 m));
+    /// assert!(cuboid
+    ///     .edges()
+    ///     .iter()
+    ///     .any(|edge| (edge.center() - point!(1 m, 0 m, 0 m)).distance_to_origin().m() < 1e-9));
+    /// ```
+    pub fn center(&self) -> Point3D {
+        let centre_of_mass = ffi::GProp_GProps_CentreOfMass(&self.linear_properties());
+        Point3D::from_m(centre_of_mass.X(), centre_of_mass.Y(), centre_of_mass.Z())
+    }
+
+    /// Return the geometric type of the curve underlying this `Edge3D`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{CurveType, Cylinder, length};
+    ///
+    /// let cylinder = Cylinder::from_radius(length!(1 m), length!(1 m));
+    /// assert!(cylinder
+    ///     .edges()
+    ///     .iter()
+    ///     .any(|edge| edge.curve_type() == CurveType::Circle));
+    /// ```
+    pub fn curve_type(&self) -> CurveType {
+        let curve = ffi::BRepAdaptor_Curve_ctor(&self.inner);
+        match curve.GetType() {
+            ffi::GeomAbs_CurveType::GeomAbs_BezierCurve => CurveType::Bezier,
+            ffi::GeomAbs_CurveType::GeomAbs_BSplineCurve => CurveType::BSpline,
+            ffi::GeomAbs_CurveType::GeomAbs_Circle => CurveType::Circle,
+            ffi::GeomAbs_CurveType::GeomAbs_Ellipse => CurveType::Ellipse,
+            ffi::GeomAbs_CurveType::GeomAbs_Hyperbola => CurveType::Hyperbola,
+            ffi::GeomAbs_CurveType::GeomAbs_Line => CurveType::Line,
+            ffi::GeomAbs_CurveType::GeomAbs_Parabola => CurveType::Parabola,
+            _ => CurveType::Other,
+        }
+    }
+
+    pub(crate) fn from_occt(edge: &ffi::TopoDS_Edge) -> Self {
+        Self {
+            inner: ffi::TopoDS_Edge_to_owned(edge),
+        }
+    }
+
+    fn linear_properties(&self) -> UniquePtr<ffi::GProp_GProps> {
+        let mut gprops = ffi::GProp_GProps_ctor();
+        ffi::BRepGProp_LinearProperties(ffi::cast_edge_to_shape(&self.inner), gprops.pin_mut());
+        gprops
+    }
+
+    fn is_reversed(&self) -> bool {
+        ffi::cast_edge_to_shape(&self.inner).Orientation()
+            == ffi::TopAbs_Orientation::TopAbs_REVERSED
+    }
+}
+
+impl Clone for Edge3D {
+    fn clone(&self) -> Self {
+        Self::from_occt(&self.inner)
+    }
+}
+
+impl PartialEq for Edge3D {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::cast_edge_to_shape(&self.inner).IsSame(ffi::cast_edge_to_shape(&other.inner))
+    }
+}
+
+impl Debug for Edge3D {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Edge3D")
+            .field("curve_type", &self.curve_type())
+            .field("start", &self.start())
+            .field("end", &self.end())
+            .finish()
+    }
+}
+
+fn point_on(curve: &ffi::BRepAdaptor_Curve, parameter: f64) -> Point3D {
+    let point = ffi::BRepAdaptor_Curve_value(curve, parameter);
+    Point3D::from_m(point.X(), point.Y(), point.Z())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cuboid, Cylinder, length};
+
+    use super::*;
+
+    #[test]
+    fn cuboid_edges_are_unique_lines() {
+        let cuboid = Cuboid::from_dim(length!(1 m), length!(2 m), length!(3 m));
+        let edges = cuboid.edges();
+        assert_eq!(edges.len(), 12);
+        assert!(
+            edges
+                .iter()
+                .all(|edge| edge.curve_type() == CurveType::Line)
+        );
+    }
+
+    #[test]
+    fn circle_length() {
+        let cylinder = Cylinder::from_radius(length!(1 m), length!(1 m));
+        let circle = cylinder
+            .edges()
+            .into_iter()
+            .find(|edge| edge.curve_type() == CurveType::Circle)
+            .unwrap();
+        assert!((circle.len().m() - core::f64::consts::TAU).abs() < 1e-9);
+    }
+}
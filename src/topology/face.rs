@@ -0,0 +1,267 @@
+use std::fmt::Debug;
+
+use cxx::UniquePtr;
+use opencascade_sys::ffi;
+
+use crate::{Area, Error, Plane, Point3D, Vec3};
+
+/// The geometric type of the surface underlying a `Face`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SurfaceType {
+    /// A Bézier surface.
+    Bezier,
+    /// A B-spline surface.
+    BSpline,
+    /// A conical surface.
+    Cone,
+    /// A cylindrical surface.
+    Cylinder,
+    /// Any surface that is not covered by the other variants, e.g. an extrusion or revolution.
+    Other,
+    /// A flat surface.
+    Plane,
+    /// A spherical surface.
+    Sphere,
+    /// A toroidal surface.
+    Torus,
+}
+
+/// A bounded surface on the boundary of a `Part`.
+///
+/// Faces are obtained from an existing `Part` through `Part::faces`.
+pub struct Face {
+    pub(crate) inner: UniquePtr<ffi::TopoDS_Face>,
+}
+impl Face {
+    /// Return the area of this `Face`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Area, Cuboid, length};
+    ///
+    /// let cuboid = Cuboid::from_dim(length!(1 m), length!(2 m), length!(3 m));
+    /// let total_area = cuboid
+    ///     .faces()
+    ///     .iter()
+    ///     .fold(Area::zero(), |total, face| total + face.area());
+    /// assert!((total_area.m2() - 22.).abs() < 1e-9);
+    /// ```
+    pub fn area(&self) -> Area {
+        Area::from_m2(self.surface_properties().Mass())
+    }
+
+    /// Return the center of mass of this `Face`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, length, point};
+    ///
+    /// let cuboid = Cuboid::from_dim(length!(2 m), length!(2 m), length!(2 m));
+    /// assert!(cuboid
+    ///     .faces()
+    ///     .iter()
+    ///     .any(|face| (face.center() - point!(0 m, 0 m, 1 m)).distance_to_origin().m() < 1e-9));
+    /// ```
+    pub fn center(&self) -> Point3D {
+        let centre_of_mass = ffi::GProp_GProps_CentreOfMass(&self.surface_properties());
+        Point3D::from_m(centre_of_mass.X(), centre_of_mass.Y(), centre_of_mass.Z())
+    }
+
+    /// Return the unit normal of this `Face` at its center, pointing out of the `Part`.
+    ///
+    /// For curved faces, the normal is evaluated at the point of the surface that is closest to
+    /// the center of the `Face`. If the surface has no well-defined normal there (e.g. at the tip
+    /// of a cone), an `Err(Error::ZeroVector)` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, length, Vec3};
+    ///
+    /// let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+    /// let normals: Vec<Vec3> = cuboid
+    ///     .faces()
+    ///     .iter()
+    ///     .map(|face| face.normal().unwrap())
+    ///     .collect();
+    /// assert!(normals.iter().any(|normal| normal.dot(Vec3::from((0., 0., 1.))) > 0.999));
+    /// assert!(normals.iter().any(|normal| normal.dot(Vec3::from((0., 0., -1.))) > 0.999));
+    /// ```
+    pub fn normal(&self) -> Result<Vec3, Error> {
+        let center = ffi::GProp_GProps_CentreOfMass(&self.surface_properties());
+        let surface = ffi::BRep_Tool_Surface(&self.inner);
+        let projector = ffi::GeomAPI_ProjectPointOnSurf_ctor(&center, &surface);
+        let mut u = 0.;
+        let mut v = 0.;
+        projector.LowerDistanceParameters(&mut u, &mut v);
+
+        let mut point = ffi::new_point(0., 0., 0.);
+        let mut normal = ffi::new_vec(0., 0., 1.);
+        ffi::BRepGProp_Face_ctor(&self.inner).Normal(u, v, point.pin_mut(), normal.pin_mut());
+        Vec3::from((normal.X(), normal.Y(), normal.Z())).normalize()
+    }
+
+    /// Return the `Plane` in which this `Face` lies.
+    ///
+    /// The origin of the `Plane` is the center of the `Face` and its normal points out of the
+    /// `Part`. The x-axis is chosen to be perpendicular to the global z-axis, so that the y-axis
+    /// points upwards on vertical faces. On faces that are perpendicular to the z-axis, the x-axis
+    /// is aligned with the global x-axis. If the `Face` is curved, an `Err(Error::NonPlanarFace)`
+    /// is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, FaceSelector, length, point, Vec3};
+    ///
+    /// let cuboid = Cuboid::from_dim(length!(2 m), length!(2 m), length!(2 m));
+    /// let top = &FaceSelector::Max(Vec3::from((0., 0., 1.))).select(&cuboid)[0];
+    /// let plane = top.plane().unwrap();
+    /// assert!((plane.normal().z - 1.).abs() < 1e-9);
+    /// assert!((plane.origin() - point!(0 m, 0 m, 1 m)).distance_to_origin().m() < 1e-9);
+    /// ```
+    pub fn plane(&self) -> Result<Plane, Error> {
+        if self.surface_type() != SurfaceType::Plane {
+            return Err(Error::NonPlanarFace);
+        }
+        let normal = self.normal()?;
+        let horizontal = Vec3::from((0., 0., 1.)).cross(normal);
+        let x_axis = if horizontal.magnitude() < 1e-9 {
+            Vec3::from((1., 0., 0.))
+        } else {
+            horizontal.normalize()?
+        };
+        let y_axis = normal.cross(x_axis);
+        Plane::new(
+            self.center(),
+            (x_axis.x, x_axis.y, x_axis.z),
+            (y_axis.x, y_axis.y, y_axis.z),
+        )
+    }
+
+    /// Return the geometric type of the surface underlying this `Face`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cylinder, length, SurfaceType};
+    ///
+    /// let cylinder = Cylinder::from_radius(length!(1 m), length!(1 m));
+    /// let types: Vec<SurfaceType> = cylinder
+    ///     .faces()
+    ///     .iter()
+    ///     .map(|face| face.surface_type())
+    ///     .collect();
+    /// assert_eq!(types.iter().filter(|t| **t == SurfaceType::Plane).count(), 2);
+    /// assert_eq!(types.iter().filter(|t| **t == SurfaceType::Cylinder).count(), 1);
+    /// ```
+    pub fn surface_type(&self) -> SurfaceType {
+        let surface = ffi::BRepAdaptor_Surface_ctor(&self.inner, true);
+        match surface.GetType() {
+            ffi::GeomAbs_SurfaceType::GeomAbs_BezierSurface => SurfaceType::Bezier,
+            ffi::GeomAbs_SurfaceType::GeomAbs_BSplineSurface => SurfaceType::BSpline,
+            ffi::GeomAbs_SurfaceType::GeomAbs_Cone => SurfaceType::Cone,
+            ffi::GeomAbs_SurfaceType::GeomAbs_Cylinder => SurfaceType::Cylinder,
+            ffi::GeomAbs_SurfaceType::GeomAbs_Plane => SurfaceType::Plane,
+            ffi::GeomAbs_SurfaceType::GeomAbs_Sphere => SurfaceType::Sphere,
+            ffi::GeomAbs_SurfaceType::GeomAbs_Torus => SurfaceType::Torus,
+            _ => SurfaceType::Other,
+        }
+    }
+
+    pub(crate) fn from_occt(face: &ffi::TopoDS_Face) -> Self {
+        Self {
+            inner: ffi::TopoDS_Face_to_owned(face),
+        }
+    }
+
+    fn surface_properties(&self) -> UniquePtr<ffi::GProp_GProps> {
+        let mut gprops = ffi::GProp_GProps_ctor();
+        ffi::BRepGProp_SurfaceProperties(ffi::cast_face_to_shape(&self.inner), gprops.pin_mut());
+        gprops
+    }
+}
+
+impl Clone for Face {
+    fn clone(&self) -> Self {
+        Self::from_occt(&self.inner)
+    }
+}
+
+impl PartialEq for Face {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::cast_face_to_shape(&self.inner).IsSame(ffi::cast_face_to_shape(&other.inner))
+    }
+}
+
+impl Debug for Face {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Face")
+            .field("surface_type", &self.surface_type())
+            .field("center", &self.center())
+            .field("area", &self.area())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cuboid, Point3D, Sphere, length};
+
+    use super::*;
+
+    #[test]
+    fn cuboid_faces_are_planar() {
+        let cuboid = Cuboid::from_dim(length!(1 m), length!(2 m), length!(3 m));
+        let faces = cuboid.faces();
+        assert_eq!(faces.len(), 6);
+        assert!(
+            faces
+                .iter()
+                .all(|face| face.surface_type() == SurfaceType::Plane)
+        );
+    }
+
+    #[test]
+    fn normal_points_outwards() {
+        let cuboid = Cuboid::from_corners(Point3D::origin(), Point3D::from_m(2, 2, 2));
+        for face in cuboid.faces() {
+            let outwards = face.center() - Point3D::from_m(1, 1, 1);
+            let outwards = Vec3::from((outwards.x.m(), outwards.y.m(), outwards.z.m()));
+            assert!((face.normal().unwrap().dot(outwards) - 1.).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn sphere_area() {
+        let sphere = Sphere::from_radius(length!(1 m));
+        let area = sphere
+            .faces()
+            .iter()
+            .fold(Area::zero(), |total, face| total + face.area());
+        assert!((area.m2() - 4. * core::f64::consts::PI).abs() < 1e-6);
+    }
+
+    #[test]
+    fn plane_of_side_face() {
+        let cuboid = Cuboid::from_corners(Point3D::origin(), Point3D::from_m(2, 2, 2));
+        for face in cuboid.faces() {
+            let plane = face.plane().unwrap();
+            assert!((plane.normal().dot(face.normal().unwrap()) - 1.).abs() < 1e-9);
+            if plane.normal().z.abs() < 1e-9 {
+                assert!((plane.y_axis().z - 1.).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn plane_of_curved_face() {
+        let sphere = Sphere::from_radius(length!(1 m));
+        assert_eq!(sphere.faces()[0].plane(), Err(Error::NonPlanarFace));
+    }
+
+    #[test]
+    fn clone_is_same_face() {
+        let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+        let faces = cuboid.faces();
+        assert_eq!(faces[0].clone(), faces[0]);
+        assert_ne!(faces[0], faces[1]);
+    }
+}
@@ -0,0 +1,9 @@
+mod edge3d;
+mod face;
+mod selector;
+mod vertex;
+
+pub use edge3d::{CurveType, Edge3D};
+pub use face::{Face, SurfaceType};
+pub use selector::{EdgeSelector, FaceSelector};
+pub use vertex::Vertex;
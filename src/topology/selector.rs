@@ -0,0 +1,445 @@
+use std::ops::Not;
+
+use crate::{Axis, Part, Point3D, Vec3};
+
+use super::{CurveType, Edge3D, Face, SurfaceType};
+
+/// Distance in meters up to which two positions are considered to be equal.
+const POSITION_TOLERANCE: f64 = 1e-7;
+/// Deviation of a dot-product between unit vectors up to which directions are considered aligned.
+const DIRECTION_TOLERANCE: f64 = 1e-9;
+
+/// A query that chooses a subset of the faces of a `Part`.
+///
+/// Selectors that rely on a direction only consider planar faces. Selectors can be combined using
+/// `and`, `or` and the `!` operator.
+///
+/// # Example
+/// ```rust
+/// use anvil::{Cuboid, FaceSelector, length, SurfaceType, Vec3};
+///
+/// let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+/// let z = Vec3::from((0., 0., 1.));
+/// assert_eq!(FaceSelector::Max(z).select(&cuboid).len(), 1);
+/// assert_eq!(FaceSelector::Parallel(z).select(&cuboid).len(), 2);
+/// assert_eq!((!FaceSelector::Parallel(z)).select(&cuboid).len(), 4);
+/// assert_eq!(
+///     FaceSelector::Type(SurfaceType::Plane)
+///         .and(FaceSelector::Perpendicular(z))
+///         .select(&cuboid)
+///         .len(),
+///     4
+/// );
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub enum FaceSelector {
+    /// Faces that are chosen by both selectors.
+    And(Box<FaceSelector>, Box<FaceSelector>),
+    /// Faces whose center lies furthest along a direction.
+    Max(Vec3),
+    /// Faces whose center lies furthest against a direction.
+    Min(Vec3),
+    /// Planar faces whose outward normal points along a direction.
+    Normal(Vec3),
+    /// Faces that are not chosen by a selector.
+    Not(Box<FaceSelector>),
+    /// Faces that are chosen by at least one of the selectors.
+    Or(Box<FaceSelector>, Box<FaceSelector>),
+    /// Planar faces whose normal is parallel to a direction, pointing either way.
+    Parallel(Vec3),
+    /// Planar faces whose normal is perpendicular to a direction.
+    Perpendicular(Vec3),
+    /// Faces whose underlying surface is of a given type.
+    Type(SurfaceType),
+}
+impl FaceSelector {
+    /// Construct a `FaceSelector::Max` along the direction of an `Axis`.
+    ///
+    /// Only the direction of the `Axis` is considered, its origin is ignored.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Axis, Cuboid, FaceSelector, length, Vec3};
+    ///
+    /// assert_eq!(
+    ///     FaceSelector::max(Axis::z()),
+    ///     FaceSelector::Max(Vec3::from((0., 0., 1.)))
+    /// );
+    /// let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+    /// assert_eq!(FaceSelector::max(Axis::neg_x()).select(&cuboid).len(), 1);
+    /// ```
+    pub fn max(axis: Axis) -> Self {
+        FaceSelector::Max(axis.direction)
+    }
+    /// Construct a `FaceSelector::Min` along the direction of an `Axis`.
+    ///
+    /// Only the direction of the `Axis` is considered, its origin is ignored.
+    pub fn min(axis: Axis) -> Self {
+        FaceSelector::Min(axis.direction)
+    }
+    /// Construct a `FaceSelector::Normal` along the direction of an `Axis`.
+    ///
+    /// Only the direction of the `Axis` is considered, its origin is ignored.
+    pub fn normal(axis: Axis) -> Self {
+        FaceSelector::Normal(axis.direction)
+    }
+    /// Construct a `FaceSelector::Parallel` to the direction of an `Axis`.
+    ///
+    /// Only the direction of the `Axis` is considered, its origin is ignored.
+    pub fn parallel(axis: Axis) -> Self {
+        FaceSelector::Parallel(axis.direction)
+    }
+    /// Construct a `FaceSelector::Perpendicular` to the direction of an `Axis`.
+    ///
+    /// Only the direction of the `Axis` is considered, its origin is ignored.
+    pub fn perpendicular(axis: Axis) -> Self {
+        FaceSelector::Perpendicular(axis.direction)
+    }
+
+    /// Return a selector that chooses the faces that are chosen by both this one and another.
+    pub fn and(self, other: FaceSelector) -> Self {
+        FaceSelector::And(Box::new(self), Box::new(other))
+    }
+
+    /// Return a selector that chooses the faces that are chosen by this one or another.
+    pub fn or(self, other: FaceSelector) -> Self {
+        FaceSelector::Or(Box::new(self), Box::new(other))
+    }
+
+    /// Return the faces of a `Part` that are chosen by this selector.
+    ///
+    /// The faces are returned in the same order as by `Part::faces`.
+    pub fn select(&self, part: &Part) -> Vec<Face> {
+        let faces = part.faces();
+        let mask = self.mask(&faces);
+        faces
+            .into_iter()
+            .zip(mask)
+            .filter(|(_, selected)| *selected)
+            .map(|(face, _)| face)
+            .collect()
+    }
+
+    /// Return for each face whether it is chosen by this selector.
+    pub(crate) fn mask(&self, faces: &[Face]) -> Vec<bool> {
+        match self {
+            FaceSelector::And(left, right) => {
+                combine(left.mask(faces), right.mask(faces), |l, r| l && r)
+            }
+            FaceSelector::Max(direction) => extremes(faces, *direction, Face::center, true),
+            FaceSelector::Min(direction) => extremes(faces, *direction, Face::center, false),
+            FaceSelector::Normal(direction) => {
+                planar_normal_mask(faces, *direction, |dot| dot > 1. - DIRECTION_TOLERANCE)
+            }
+            FaceSelector::Not(selector) => selector.mask(faces).into_iter().map(|s| !s).collect(),
+            FaceSelector::Or(left, right) => {
+                combine(left.mask(faces), right.mask(faces), |l, r| l || r)
+            }
+            FaceSelector::Parallel(direction) => planar_normal_mask(faces, *direction, |dot| {
+                dot.abs() > 1. - DIRECTION_TOLERANCE
+            }),
+            FaceSelector::Perpendicular(direction) => {
+                planar_normal_mask(faces, *direction, |dot| dot.abs() < DIRECTION_TOLERANCE)
+            }
+            FaceSelector::Type(surface_type) => faces
+                .iter()
+                .map(|face| face.surface_type() == *surface_type)
+                .collect(),
+        }
+    }
+}
+
+impl Not for FaceSelector {
+    type Output = FaceSelector;
+    fn not(self) -> FaceSelector {
+        FaceSelector::Not(Box::new(self))
+    }
+}
+
+/// A query that chooses a subset of the edges of a `Part`.
+///
+/// Selectors that rely on a direction only consider straight edges. Selectors can be combined
+/// using `and`, `or` and the `!` operator.
+///
+/// # Example
+/// ```rust
+/// use anvil::{CurveType, Cuboid, Cylinder, EdgeSelector, length, Vec3};
+///
+/// let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+/// let z = Vec3::from((0., 0., 1.));
+/// assert_eq!(EdgeSelector::Parallel(z).select(&cuboid).len(), 4);
+/// assert_eq!(EdgeSelector::Max(z).select(&cuboid).len(), 4);
+/// assert_eq!(
+///     EdgeSelector::Max(z)
+///         .or(EdgeSelector::Min(z))
+///         .select(&cuboid)
+///         .len(),
+///     8
+/// );
+///
+/// let cylinder = Cylinder::from_radius(length!(1 m), length!(1 m));
+/// assert_eq!(EdgeSelector::Type(CurveType::Circle).select(&cylinder).len(), 2);
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub enum EdgeSelector {
+    /// Edges that are chosen by both selectors.
+    And(Box<EdgeSelector>, Box<EdgeSelector>),
+    /// Edges whose center lies furthest along a direction.
+    Max(Vec3),
+    /// Edges whose center lies furthest against a direction.
+    Min(Vec3),
+    /// Edges that are not chosen by a selector.
+    Not(Box<EdgeSelector>),
+    /// Edges that are chosen by at least one of the selectors.
+    Or(Box<EdgeSelector>, Box<EdgeSelector>),
+    /// Straight edges that run parallel to a direction.
+    Parallel(Vec3),
+    /// Straight edges that run perpendicular to a direction.
+    Perpendicular(Vec3),
+    /// Edges whose underlying curve is of a given type.
+    Type(CurveType),
+}
+impl EdgeSelector {
+    /// Construct an `EdgeSelector::Max` along the direction of an `Axis`.
+    ///
+    /// Only the direction of the `Axis` is considered, its origin is ignored.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Axis, Cuboid, EdgeSelector, length, Vec3};
+    ///
+    /// assert_eq!(
+    ///     EdgeSelector::max(Axis::z()),
+    ///     EdgeSelector::Max(Vec3::from((0., 0., 1.)))
+    /// );
+    /// let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+    /// assert_eq!(EdgeSelector::max(Axis::z()).select(&cuboid).len(), 4);
+    /// ```
+    pub fn max(axis: Axis) -> Self {
+        EdgeSelector::Max(axis.direction)
+    }
+    /// Construct an `EdgeSelector::Min` along the direction of an `Axis`.
+    ///
+    /// Only the direction of the `Axis` is considered, its origin is ignored.
+    pub fn min(axis: Axis) -> Self {
+        EdgeSelector::Min(axis.direction)
+    }
+    /// Construct an `EdgeSelector::Parallel` to the direction of an `Axis`.
+    ///
+    /// Only the direction of the `Axis` is considered, its origin is ignored.
+    pub fn parallel(axis: Axis) -> Self {
+        EdgeSelector::Parallel(axis.direction)
+    }
+    /// Construct an `EdgeSelector::Perpendicular` to the direction of an `Axis`.
+    ///
+    /// Only the direction of the `Axis` is considered, its origin is ignored.
+    pub fn perpendicular(axis: Axis) -> Self {
+        EdgeSelector::Perpendicular(axis.direction)
+    }
+
+    /// Return a selector that chooses the edges that are chosen by both this one and another.
+    pub fn and(self, other: EdgeSelector) -> Self {
+        EdgeSelector::And(Box::new(self), Box::new(other))
+    }
+
+    /// Return a selector that chooses the edges that are chosen by this one or another.
+    pub fn or(self, other: EdgeSelector) -> Self {
+        EdgeSelector::Or(Box::new(self), Box::new(other))
+    }
+
+    /// Return the edges of a `Part` that are chosen by this selector.
+    ///
+    /// The edges are returned in the same order as by `Part::edges`.
+    pub fn select(&self, part: &Part) -> Vec<Edge3D> {
+        let edges = part.edges();
+        let mask = self.mask(&edges);
+        edges
+            .into_iter()
+            .zip(mask)
+            .filter(|(_, selected)| *selected)
+            .map(|(edge, _)| edge)
+            .collect()
+    }
+
+    /// Return for each edge whether it is chosen by this selector.
+    pub(crate) fn mask(&self, edges: &[Edge3D]) -> Vec<bool> {
+        match self {
+            EdgeSelector::And(left, right) => {
+                combine(left.mask(edges), right.mask(edges), |l, r| l && r)
+            }
+            EdgeSelector::Max(direction) => extremes(edges, *direction, Edge3D::center, true),
+            EdgeSelector::Min(direction) => extremes(edges, *direction, Edge3D::center, false),
+            EdgeSelector::Not(selector) => selector.mask(edges).into_iter().map(|s| !s).collect(),
+            EdgeSelector::Or(left, right) => {
+                combine(left.mask(edges), right.mask(edges), |l, r| l || r)
+            }
+            EdgeSelector::Parallel(direction) => line_direction_mask(edges, *direction, |dot| {
+                dot.abs() > 1. - DIRECTION_TOLERANCE
+            }),
+            EdgeSelector::Perpendicular(direction) => {
+                line_direction_mask(edges, *direction, |dot| dot.abs() < DIRECTION_TOLERANCE)
+            }
+            EdgeSelector::Type(curve_type) => edges
+                .iter()
+                .map(|edge| edge.curve_type() == *curve_type)
+                .collect(),
+        }
+    }
+}
+
+impl Not for EdgeSelector {
+    type Output = EdgeSelector;
+    fn not(self) -> EdgeSelector {
+        EdgeSelector::Not(Box::new(self))
+    }
+}
+
+fn combine(left: Vec<bool>, right: Vec<bool>, operation: fn(bool, bool) -> bool) -> Vec<bool> {
+    left.into_iter()
+        .zip(right)
+        .map(|(l, r)| operation(l, r))
+        .collect()
+}
+
+/// Mark the items whose center lies furthest along (or against) a direction.
+fn extremes<T>(items: &[T], direction: Vec3, center: fn(&T) -> Point3D, along: bool) -> Vec<bool> {
+    let direction = match direction.normalize() {
+        Ok(direction) => direction,
+        Err(_) => return vec![false; items.len()],
+    };
+    let sign = if along { 1. } else { -1. };
+    let positions: Vec<f64> = items
+        .iter()
+        .map(|item| {
+            let center = center(item);
+            sign * (center.x.m() * direction.x
+                + center.y.m() * direction.y
+                + center.z.m() * direction.z)
+        })
+        .collect();
+    let extreme = positions.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    positions
+        .into_iter()
+        .map(|position| extreme - position < POSITION_TOLERANCE)
+        .collect()
+}
+
+/// Mark the planar faces whose normal fulfills a condition on its dot-product with a direction.
+fn planar_normal_mask(faces: &[Face], direction: Vec3, condition: fn(f64) -> bool) -> Vec<bool> {
+    let direction = match direction.normalize() {
+        Ok(direction) => direction,
+        Err(_) => return vec![false; faces.len()],
+    };
+    faces
+        .iter()
+        .map(|face| {
+            face.surface_type() == SurfaceType::Plane
+                && face
+                    .normal()
+                    .is_ok_and(|normal| condition(normal.dot(direction)))
+        })
+        .collect()
+}
+
+/// Mark the straight edges whose direction fulfills a condition on its dot-product with another
+/// direction.
+fn line_direction_mask(edges: &[Edge3D], direction: Vec3, condition: fn(f64) -> bool) -> Vec<bool> {
+    let direction = match direction.normalize() {
+        Ok(direction) => direction,
+        Err(_) => return vec![false; edges.len()],
+    };
+    edges
+        .iter()
+        .map(|edge| {
+            if edge.curve_type() != CurveType::Line {
+                return false;
+            }
+            let diff = edge.end() - edge.start();
+            Vec3::from((diff.x.m(), diff.y.m(), diff.z.m()))
+                .normalize()
+                .is_ok_and(|edge_direction| condition(edge_direction.dot(direction)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cuboid, Cylinder, Point3D, length};
+
+    use super::*;
+
+    #[test]
+    fn max_face_of_cuboid() {
+        let cuboid = Cuboid::from_corners(Point3D::origin(), Point3D::from_m(1, 2, 3));
+        let top = FaceSelector::Max(Vec3::from((0., 0., 1.))).select(&cuboid);
+        assert_eq!(top.len(), 1);
+        assert!(
+            (top[0].center() - Point3D::from_m(0.5, 1., 3.))
+                .distance_to_origin()
+                .m()
+                < 1e-9
+        );
+    }
+
+    #[test]
+    fn normal_selects_one_side() {
+        let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+        let selected = FaceSelector::Normal(Vec3::from((0., -1., 0.))).select(&cuboid);
+        assert_eq!(selected.len(), 1);
+        assert!(selected[0].center().y.m() < 0.);
+    }
+
+    #[test]
+    fn not_is_complement() {
+        let cylinder = Cylinder::from_radius(length!(1 m), length!(2 m));
+        let planar = FaceSelector::Type(SurfaceType::Plane);
+        assert_eq!(planar.clone().select(&cylinder).len(), 2);
+        assert_eq!((!planar).select(&cylinder).len(), 1);
+    }
+
+    #[test]
+    fn zero_direction_selects_nothing() {
+        let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+        let zero = Vec3::from((0., 0., 0.));
+        assert!(FaceSelector::Max(zero).select(&cuboid).is_empty());
+        assert!(EdgeSelector::Parallel(zero).select(&cuboid).is_empty());
+    }
+
+    #[test]
+    fn axis_constructors_ignore_origin() {
+        let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+        let axis = Axis::new(Point3D::from_m(5., 5., 5.), (0., 0., 2.));
+        assert_eq!(FaceSelector::normal(axis.clone()).select(&cuboid).len(), 1);
+        assert_eq!(FaceSelector::min(axis.clone()).select(&cuboid).len(), 1);
+        assert_eq!(
+            FaceSelector::parallel(axis.clone()).select(&cuboid).len(),
+            2
+        );
+        assert_eq!(
+            FaceSelector::perpendicular(axis.clone())
+                .select(&cuboid)
+                .len(),
+            4
+        );
+        assert_eq!(EdgeSelector::min(axis.clone()).select(&cuboid).len(), 4);
+        assert_eq!(
+            EdgeSelector::parallel(axis.clone()).select(&cuboid).len(),
+            4
+        );
+        assert_eq!(EdgeSelector::perpendicular(axis).select(&cuboid).len(), 8);
+    }
+
+    #[test]
+    fn top_edges_are_not_vertical() {
+        let cuboid = Cuboid::from_dim(length!(1 m), length!(1 m), length!(1 m));
+        let z = Vec3::from((0., 0., 1.));
+        let selector = EdgeSelector::Max(z).and(!EdgeSelector::Parallel(z));
+        assert_eq!(selector.select(&cuboid).len(), 4);
+        assert!(
+            EdgeSelector::Max(z)
+                .and(EdgeSelector::Parallel(z))
+                .select(&cuboid)
+                .is_empty()
+        );
+    }
+}
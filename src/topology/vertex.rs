@@ -0,0 +1,54 @@
+use std::fmt::Debug;
+
+use cxx::UniquePtr;
+use opencascade_sys::ffi;
+
+use crate::Point3D;
+
+/// A corner point on the boundary of a `Part`.
+///
+/// Vertices are obtained from an existing `Part` through `Part::vertices`.
+pub struct Vertex {
+    pub(crate) inner: UniquePtr<ffi::TopoDS_Vertex>,
+}
+impl Vertex {
+    /// Return the position of this `Vertex`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use anvil::{Cuboid, point};
+    ///
+    /// let cuboid = Cuboid::from_corners(point!(0 m, 0 m, 0 m), point!(1 m, 2 m, 3 m));
+    /// assert!(cuboid.vertices().iter().any(|vertex| vertex.point() == point!(1 m, 2 m, 3 m)));
+    /// ```
+    pub fn point(&self) -> Point3D {
+        let point = ffi::BRep_Tool_Pnt(&self.inner);
+        Point3D::from_m(point.X(), point.Y(), point.Z())
+    }
+
+    pub(crate) fn from_occt(vertex: &ffi::TopoDS_Vertex) -> Self {
+        Self {
+            inner: ffi::TopoDS_Vertex_to_owned(vertex),
+        }
+    }
+}
+
+impl Clone for Vertex {
+    fn clone(&self) -> Self {
+        Self::from_occt(&self.inner)
+    }
+}
+
+impl PartialEq for Vertex {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::cast_vertex_to_shape(&self.inner).IsSame(ffi::cast_vertex_to_shape(&other.inner))
+    }
+}
+
+impl Debug for Vertex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Vertex")
+            .field("point", &self.point())
+            .finish()
+    }
+}